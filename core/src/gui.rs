@@ -1,8 +1,10 @@
+use crate::textures::texture_coverage;
 use derive_macros_helpers::{Bounded, Enumerable};
-use log::error;
+use log::{error, warn};
 use nannou::prelude::*;
 use nannou_egui::Egui;
 use std::fmt::Debug;
+use std::marker::PhantomData;
 
 pub trait Application<'a> {
     type ViewResult;
@@ -20,24 +22,83 @@ pub trait Application<'a> {
     ) -> Self::UpdateResult;
 }
 
-pub type TextureMap<K> = std::collections::btree_map::BTreeMap<K, wgpu::Texture>;
+/// Textures stored densely by the [`Enumerable::to_index`] of their key
+pub struct TextureMap<K> {
+    textures: Vec<Option<wgpu::Texture>>,
+    key: PhantomData<K>,
+}
+
+impl<K: Enumerable> TextureMap<K> {
+    pub fn new() -> Self {
+        TextureMap {
+            textures: Vec::with_capacity(K::COUNT.unwrap_or(0)),
+            key: PhantomData,
+        }
+    }
 
-pub fn load_textures<K: Ord + Debug + Enumerable + Bounded + ToString>(app: &App) -> TextureMap<K> {
+    pub fn insert(&mut self, key: &K, texture: wgpu::Texture) -> Option<wgpu::Texture> {
+        let index = key.to_index();
+        if index >= self.textures.len() {
+            self.textures.resize_with(index + 1, || None);
+        }
+        self.textures[index].replace(texture)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&wgpu::Texture> {
+        self.textures.get(key.try_to_index()?)?.as_ref()
+    }
+}
+
+impl<K: Enumerable> Default for TextureMap<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Loads the texture of every key `K` that has one, the missing textures are logged once
+pub fn load_textures<K: PartialEq + Debug + Enumerable + Bounded + ToString>(
+    app: &App,
+) -> TextureMap<K> {
     let mut texture_map = <TextureMap<_>>::new();
 
     let texture_assets = app.assets_path().unwrap().join("textures");
 
-    for tile_index in K::enumerate_all() {
+    let coverage = match texture_coverage::<K>(&texture_assets) {
+        Ok(coverage) => coverage,
+        Err(err) => {
+            error!(
+                "Failed reading the textures at {:?}: {}",
+                texture_assets.display(),
+                err
+            );
+            return texture_map;
+        }
+    };
+    if !coverage.missing.is_empty() {
+        warn!(
+            "{} of {} textures are missing: {:?}",
+            coverage.missing.len(),
+            coverage.missing.len() + coverage.present.len(),
+            coverage.missing
+        );
+    }
+    if !coverage.unexpected.is_empty() {
+        warn!("Textures without a key: {:?}", coverage.unexpected);
+    }
+
+    for tile_index in coverage.present {
         let file_name = tile_index.to_string();
         let path = texture_assets.join(format!("{file_name}.png"));
-        if let Ok(texture) = wgpu::Texture::from_path(app, &path) {
-            texture_map.insert(tile_index, texture);
-        } else {
-            error!(
-                "Failed loading Texture with Index: {:?} , at: {:?}",
+        match wgpu::Texture::from_path(app, &path) {
+            Ok(texture) => {
+                texture_map.insert(&tile_index, texture);
+            }
+            Err(err) => error!(
+                "Failed loading Texture with Index: {:?} , at: {:?}: {}",
                 &tile_index,
-                path.display()
-            );
+                path.display(),
+                err
+            ),
         }
     }
 
@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 pub mod error;
 pub mod gui;
+pub mod textures;
 
 pub fn get_asset_path() -> PathBuf {
     find_folder::Search::KidsThenParents(3, 5)
@@ -0,0 +1,79 @@
+//! Inspection of the texture directory, independent of the GPU.
+
+use derive_macros_helpers::{Bounded, Enumerable};
+use std::collections::BTreeSet;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The `.png` files of a texture directory in the alphabetical order of their file names,
+/// split by whether they are named after a key
+#[derive(Debug)]
+pub struct IdentifiedTextures<K> {
+    /// The file stems that parse to a key, together with the key
+    pub identified: Vec<(String, K)>,
+    /// The file stems that do not parse to a key
+    pub orphans: Vec<String>,
+}
+
+/// The stems of the `.png` files in `directory` in alphabetical order
+pub fn texture_file_names(directory: &Path) -> io::Result<Vec<String>> {
+    let mut names = vec![];
+    for entry in std::fs::read_dir(directory)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "png") {
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(stem.to_string());
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+/// Parses the name of every texture in `directory` as a `K`
+pub fn identify_textures<K: FromStr>(directory: &Path) -> io::Result<IdentifiedTextures<K>> {
+    let mut identified = vec![];
+    let mut orphans = vec![];
+    for name in texture_file_names(directory)? {
+        match name.parse() {
+            Ok(key) => identified.push((name, key)),
+            Err(_) => orphans.push(name),
+        }
+    }
+    Ok(IdentifiedTextures {
+        identified,
+        orphans,
+    })
+}
+
+/// Which textures of the keys `K` exist in a texture directory
+#[derive(Debug)]
+pub struct TextureCoverage<K> {
+    pub present: Vec<K>,
+    pub missing: Vec<K>,
+    /// The file stems that are not the name of any key
+    pub unexpected: Vec<String>,
+}
+
+/// Compares the `.png` files in `directory` with the names of all keys `K`
+pub fn texture_coverage<K>(directory: &Path) -> io::Result<TextureCoverage<K>>
+where
+    K: PartialEq + Enumerable + Bounded + ToString,
+{
+    let mut unexpected: BTreeSet<_> = texture_file_names(directory)?.into_iter().collect();
+    let mut present = vec![];
+    let mut missing = vec![];
+    for key in K::enumerate_all() {
+        if unexpected.remove(&key.to_string()) {
+            present.push(key);
+        } else {
+            missing.push(key);
+        }
+    }
+    Ok(TextureCoverage {
+        present,
+        missing,
+        unexpected: unexpected.into_iter().collect(),
+    })
+}
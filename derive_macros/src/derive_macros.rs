@@ -1,2 +1,38 @@
+pub mod asset_name;
 pub mod bounded;
 pub mod enumerable;
+
+mod attributes;
+mod fields;
+
+use proc_macro2::TokenStream;
+use syn::punctuated::Punctuated;
+use syn::{parse_quote, DeriveInput, GenericParam, Generics, Token, TypeParamBound};
+
+/// Adds the `+` separated `bounds` to every type parameter of `generics`
+pub fn add_trait_bounds(generics: &Generics, bounds: TokenStream) -> Generics {
+    let bounds: Punctuated<TypeParamBound, Token![+]> = parse_quote!(#bounds);
+    let mut generics = generics.clone();
+    for param in &mut generics.params {
+        if let GenericParam::Type(type_param) = param {
+            type_param.bounds.extend(bounds.iter().cloned());
+        }
+    }
+    generics
+}
+
+/// The error for deriving `trait_name` for an enum without variants to enumerate
+pub fn no_variants_error(ast: &DeriveInput, trait_name: &str, any_skipped: bool) -> syn::Error {
+    let message = if any_skipped {
+        format!(
+            "can not derive {} for `{}` as all of its variants are skipped",
+            trait_name, ast.ident
+        )
+    } else {
+        format!(
+            "can not derive {} for `{}` as it has no variants",
+            trait_name, ast.ident
+        )
+    };
+    syn::Error::new_spanned(&ast.ident, message)
+}
@@ -0,0 +1,388 @@
+//! Derives `Display`, `FromStr` and `AssetName` from `#[asset(...)]` attributes.
+//!
+//! - `format = "<format>"` on a struct or enum variant gives the name of its values,
+//!   `{<field>}` is replaced by the name of the field and `{{`/`}}` escape braces.
+//!   Unit structs and variants default to the snake case version of their name.
+//! - `when_true = "<name>", when_false = "<name>"` on a `bool` field gives the name of the field.
+//!
+//! Every field has to appear in the format exactly once, so that the name can be parsed again.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Attribute, Data::*, DeriveInput, Fields, Ident, LitStr, Type};
+
+pub fn impl_asset_name_derive(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &ast.ident;
+    let span = ident.span();
+
+    let named = match &ast.data {
+        Struct(data) => vec![NamedValue::parse(
+            quote! {Self},
+            ident,
+            &ast.attrs,
+            &data.fields,
+        )?],
+        Enum(data) => {
+            reject_format(&ast.attrs)?;
+            data.variants
+                .iter()
+                .map(|variant| {
+                    let variant_ident = &variant.ident;
+                    NamedValue::parse(
+                        quote! {Self::#variant_ident},
+                        variant_ident,
+                        &variant.attrs,
+                        &variant.fields,
+                    )
+                })
+                .collect::<syn::Result<_>>()?
+        }
+        Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "AssetName can not be derived for unions",
+            ))
+        }
+    };
+
+    let display_arms = named.iter().map(NamedValue::display_arm);
+    let parsers = named.iter().map(NamedValue::parser);
+
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let result = quote_spanned! {span=>
+
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, __formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                match self {
+                    #(#display_arms),*
+                }
+                Ok(())
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = derive_macros_helpers::ParseAssetNameError;
+
+            fn from_str(__name: &str) -> Result<Self, Self::Err> {
+                #(#parsers)*
+                Err(derive_macros_helpers::ParseAssetNameError::new::<Self>(__name))
+            }
+        }
+
+        impl #impl_generics derive_macros_helpers::AssetName for #ident #ty_generics #where_clause {}
+    };
+    Ok(result)
+}
+
+/// How a single field is written into and read from a name
+enum FieldName {
+    Display,
+    Bool {
+        when_true: LitStr,
+        when_false: LitStr,
+    },
+}
+
+struct Field<'a> {
+    binding: Ident,
+    ty: &'a Type,
+    name: FieldName,
+}
+
+enum Segment {
+    Literal(String),
+    /// The index of a field in declaration order
+    Field(usize),
+}
+
+/// A struct or enum variant together with the format of its name
+struct NamedValue<'a> {
+    path: TokenStream,
+    fields: &'a Fields,
+    field_list: Vec<Field<'a>>,
+    segments: Vec<Segment>,
+}
+
+impl<'a> NamedValue<'a> {
+    fn parse(
+        path: TokenStream,
+        ident: &Ident,
+        attrs: &[Attribute],
+        fields: &'a Fields,
+    ) -> syn::Result<Self> {
+        let field_list = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                Ok(Field {
+                    binding: match &field.ident {
+                        Some(ident) => ident.clone(),
+                        None => Ident::new(&format!("index{}", index), Span::call_site()),
+                    },
+                    ty: &field.ty,
+                    name: parse_field_attributes(&field.attrs)?,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let segments = match parse_format_attribute(attrs)? {
+            Some(format) => parse_format(&format, fields)?,
+            None if fields.is_empty() => vec![Segment::Literal(snake_case(&ident.to_string()))],
+            None => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "a `#[asset(format = \"...\")]` is required for values with fields",
+                ))
+            }
+        };
+
+        Ok(NamedValue {
+            path,
+            fields,
+            field_list,
+            segments,
+        })
+    }
+
+    fn pattern(&self) -> TokenStream {
+        let path = &self.path;
+        let bindings = self.field_list.iter().map(|field| &field.binding);
+        match self.fields {
+            Fields::Unit => quote! {#path},
+            Fields::Named(_) => quote! {#path{#(#bindings),*}},
+            Fields::Unnamed(_) => quote! {#path(#(#bindings),*)},
+        }
+    }
+
+    fn display_arm(&self) -> TokenStream {
+        let pattern = self.pattern();
+        let writes = self.segments.iter().map(|segment| match segment {
+            Segment::Literal(literal) => quote! {__formatter.write_str(#literal)?;},
+            Segment::Field(index) => {
+                let field = &self.field_list[*index];
+                let binding = &field.binding;
+                match &field.name {
+                    FieldName::Display => {
+                        quote! {::std::fmt::Display::fmt(#binding, __formatter)?;}
+                    }
+                    FieldName::Bool {
+                        when_true,
+                        when_false,
+                    } => quote! {
+                        __formatter.write_str(if *#binding { #when_true } else { #when_false })?;
+                    },
+                }
+            }
+        });
+        quote! {
+            #pattern => {
+                #(#writes)*
+            }
+        }
+    }
+
+    /// Returns from `from_str` if `name` matches the format
+    fn parser(&self) -> TokenStream {
+        let mut literals = vec![String::new()];
+        let mut captured = vec![];
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => literals.last_mut().unwrap().push_str(literal),
+                Segment::Field(index) => {
+                    captured.push(&self.field_list[*index]);
+                    literals.push(String::new());
+                }
+            }
+        }
+
+        let captures: Vec<_> = (0..captured.len())
+            .map(|index| Ident::new(&format!("__capture{}", index), Span::call_site()))
+            .collect();
+        let bindings = captured.iter().map(|field| &field.binding);
+        let parsed = captured
+            .iter()
+            .zip(&captures)
+            .map(|(field, capture)| match &field.name {
+                FieldName::Display => {
+                    let ty = field.ty;
+                    quote! {<#ty as ::std::str::FromStr>::from_str(#capture).ok()}
+                }
+                FieldName::Bool {
+                    when_true,
+                    when_false,
+                } => quote! {
+                    match *#capture {
+                        #when_true => Some(true),
+                        #when_false => Some(false),
+                        _ => None,
+                    }
+                },
+            });
+        let pattern = self.pattern();
+
+        if captured.is_empty() {
+            let literal = &literals[0];
+            return quote! {
+                if __name == #literal {
+                    return Ok(#pattern);
+                }
+            };
+        }
+
+        quote! {
+            for __captures in derive_macros_helpers::split_format(__name, &[#(#literals),*]) {
+                if let [#(#captures),*] = __captures.as_slice() {
+                    if let (#(Some(#bindings),)*) = (#(#parsed,)*) {
+                        return Ok(#pattern);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn reject_format(attrs: &[Attribute]) -> syn::Result<()> {
+    match parse_format_attribute(attrs)? {
+        Some(format) => Err(syn::Error::new_spanned(
+            format,
+            "`format` is only supported on structs and enum variants",
+        )),
+        None => Ok(()),
+    }
+}
+
+fn parse_format_attribute(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    let mut format = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("asset")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("format") {
+                if format.is_some() {
+                    return Err(meta.error("duplicate key `format`"));
+                }
+                format = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown key, expected `format`"))
+            }
+        })?;
+    }
+    Ok(format)
+}
+
+fn parse_field_attributes(attrs: &[Attribute]) -> syn::Result<FieldName> {
+    let mut when_true: Option<LitStr> = None;
+    let mut when_false: Option<LitStr> = None;
+    let mut first_span = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("asset")) {
+        first_span.get_or_insert(attr.path().get_ident().unwrap().span());
+        attr.parse_nested_meta(|meta| {
+            let slot = if meta.path.is_ident("when_true") {
+                &mut when_true
+            } else if meta.path.is_ident("when_false") {
+                &mut when_false
+            } else {
+                return Err(meta.error("unknown key, expected `when_true` or `when_false`"));
+            };
+            if slot.is_some() {
+                return Err(meta.error("duplicate key"));
+            }
+            *slot = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+    }
+    match (when_true, when_false) {
+        (None, None) => Ok(FieldName::Display),
+        (Some(when_true), Some(when_false)) => {
+            if when_true.value() == when_false.value() {
+                Err(syn::Error::new_spanned(
+                    when_false,
+                    "`when_true` and `when_false` must differ",
+                ))
+            } else {
+                Ok(FieldName::Bool {
+                    when_true,
+                    when_false,
+                })
+            }
+        }
+        _ => Err(syn::Error::new(
+            first_span.unwrap(),
+            "`when_true` and `when_false` have to be given together",
+        )),
+    }
+}
+
+fn parse_format(format: &LitStr, fields: &Fields) -> syn::Result<Vec<Segment>> {
+    let error = |message: String| syn::Error::new_spanned(format, message);
+
+    let field_names: Vec<String> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => index.to_string(),
+        })
+        .collect();
+
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut used = vec![false; field_names.len()];
+    let value = format.value();
+    let mut chars = value.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let placeholder: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                let index = field_names
+                    .iter()
+                    .position(|name| *name == placeholder)
+                    .ok_or_else(|| error(format!("unknown field `{}` in format", placeholder)))?;
+                if std::mem::replace(&mut used[index], true) {
+                    return Err(error(format!(
+                        "field `{}` appears more than once in the format",
+                        placeholder
+                    )));
+                }
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+                segments.push(Segment::Field(index));
+            }
+            '}' => return Err(error("unmatched `}` in format".to_string())),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    if let Some(index) = used.iter().position(|used| !used) {
+        return Err(error(format!(
+            "field `{}` does not appear in the format",
+            field_names[index]
+        )));
+    }
+
+    Ok(segments)
+}
+
+fn snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() && index != 0 {
+            result.push('_');
+        }
+        result.extend(c.to_lowercase());
+    }
+    result
+}
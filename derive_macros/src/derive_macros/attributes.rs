@@ -0,0 +1,185 @@
+//! Parsing of the `#[bounded(...)]` and `#[enumerable(...)]` helper attributes.
+//!
+//! Both namespaces accept the same keys and are read by both derives,
+//! as the values a type can take have to agree between `Bounded` and `Enumerable`:
+//!
+//! - `skip` on an enum variant excludes the variant
+//! - `min = <int>, max = <int>` on an integer field restricts the field to the inclusive range
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, Expr, ExprLit, ExprUnary, Lit, UnOp};
+
+const NAMESPACES: [&str; 2] = ["bounded", "enumerable"];
+
+#[derive(Default)]
+struct Options {
+    skip: Option<Span>,
+    min: Option<(i128, Span)>,
+    max: Option<(i128, Span)>,
+}
+
+impl Options {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut options = Options::default();
+
+        for attr in attrs
+            .iter()
+            .filter(|attr| NAMESPACES.iter().any(|ns| attr.path().is_ident(ns)))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    if options.skip.is_some() {
+                        return Err(meta.error("duplicate key `skip`"));
+                    }
+                    options.skip = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("min") {
+                    parse_int_value(&meta, &mut options.min, "min")
+                } else if meta.path.is_ident("max") {
+                    parse_int_value(&meta, &mut options.max, "max")
+                } else {
+                    Err(meta.error("unknown key, expected one of `skip`, `min` or `max`"))
+                }
+            })?;
+        }
+
+        Ok(options)
+    }
+
+    fn reject_skip(&self) -> syn::Result<()> {
+        match self.skip {
+            Some(span) => Err(syn::Error::new(
+                span,
+                "`skip` is only supported on enum variants",
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn reject_range(&self) -> syn::Result<()> {
+        match self.min.or(self.max) {
+            Some((_, span)) => Err(syn::Error::new(
+                span,
+                "`min` and `max` are only supported on fields",
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_int_value(
+    meta: &ParseNestedMeta,
+    slot: &mut Option<(i128, Span)>,
+    key: &str,
+) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error(format!("duplicate key `{}`", key)));
+    }
+    let expr: Expr = meta.value()?.parse()?;
+    *slot = Some((parse_int(&expr)?, expr.span()));
+    Ok(())
+}
+
+fn parse_int(expr: &Expr) -> syn::Result<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(-parse_int(expr)?),
+        _ => Err(syn::Error::new_spanned(expr, "expected an integer literal")),
+    }
+}
+
+/// Validates the attributes on the derived type itself, none of the keys apply there
+pub fn validate_container(attrs: &[Attribute]) -> syn::Result<()> {
+    let options = Options::parse(attrs)?;
+    options.reject_skip()?;
+    options.reject_range()
+}
+
+/// Whether an enum variant is excluded from the values of the type
+pub fn is_skipped(attrs: &[Attribute]) -> syn::Result<bool> {
+    let options = Options::parse(attrs)?;
+    options.reject_range()?;
+    Ok(options.skip.is_some())
+}
+
+/// The inclusive range a field is restricted to, if any
+pub fn field_range(attrs: &[Attribute]) -> syn::Result<Option<Range>> {
+    let options = Options::parse(attrs)?;
+    options.reject_skip()?;
+    match (options.min, options.max) {
+        (None, None) => Ok(None),
+        (Some((_, span)), None) => Err(syn::Error::new(span, "`min` requires a `max`")),
+        (None, Some((_, span))) => Err(syn::Error::new(span, "`max` requires a `min`")),
+        (Some(min), Some(max)) => Range::new(min, max).map(Some),
+    }
+}
+
+/// An inclusive range of integers given by a `min` and `max` key
+pub struct Range {
+    min: (i128, Span),
+    max: (i128, Span),
+    count: usize,
+}
+
+impl Range {
+    fn new(min: (i128, Span), max: (i128, Span)) -> syn::Result<Self> {
+        if min.0 > max.0 {
+            return Err(syn::Error::new(
+                max.1,
+                "`max` must not be smaller than `min`",
+            ));
+        }
+        let count = max
+            .0
+            .abs_diff(min.0)
+            .checked_add(1)
+            .and_then(|count| usize::try_from(count).ok())
+            .ok_or_else(|| {
+                syn::Error::new(
+                    max.1,
+                    "the number of values in the range must fit into a usize",
+                )
+            })?;
+        Ok(Range { min, max, count })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn min(&self) -> TokenStream {
+        int_tokens(self.min)
+    }
+
+    pub fn max(&self) -> TokenStream {
+        int_tokens(self.max)
+    }
+
+    /// `min` as an `i128` literal, for computing offsets into the range
+    pub fn min_i128(&self) -> TokenStream {
+        let (value, span) = self.min;
+        let mut literal = Literal::i128_suffixed(value);
+        literal.set_span(span);
+        quote! {#literal}
+    }
+}
+
+/// An unsuffixed literal, so that its type is inferred from the field it is used for
+fn int_tokens((value, span): (i128, Span)) -> TokenStream {
+    let mut literal = Literal::u128_unsuffixed(value.unsigned_abs());
+    literal.set_span(span);
+    if value < 0 {
+        quote! {-#literal}
+    } else {
+        quote! {#literal}
+    }
+}
@@ -1,53 +1,182 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{Data::*, DataEnum, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Ident, Variant};
+use syn::{Data::*, DataUnion, DeriveInput};
 
-pub fn impl_enumerable_trait_derive(ast: &DeriveInput) -> proc_macro::TokenStream {
+use super::attributes;
+use super::fields::{BoundedField, BoundedFields, BoundedVariant, BoundedVariants};
+
+mod index;
+
+/// The direction in which the generated code steps through the values of a type
+#[derive(Clone, Copy)]
+enum Step {
+    /// Step towards `Bounded::maximum`, resetting carried fields to their minimum
+    Next,
+    /// Step towards `Bounded::minimum`, resetting carried fields to their maximum
+    Previous,
+}
+
+impl Step {
+    fn method(self) -> TokenStream {
+        match self {
+            Step::Next => quote! {next},
+            Step::Previous => quote! {previous},
+        }
+    }
+
+    fn reset_field(self, field: &BoundedField) -> TokenStream {
+        match self {
+            Step::Next => field.minimum(),
+            Step::Previous => field.maximum(),
+        }
+    }
+
+    fn reset_variant(self, variant: &BoundedVariant) -> TokenStream {
+        match self {
+            Step::Next => variant.fields.minimum(&variant.path()),
+            Step::Previous => variant.fields.maximum(&variant.path()),
+        }
+    }
+
+    /// Steps a single bound field, evaluates to an `Option` of the field type
+    fn step_field(self, field: &BoundedField) -> TokenStream {
+        let method = self.method();
+        let binding = &field.binding;
+        match &field.range {
+            Some(range) => {
+                let limit = match self {
+                    Step::Next => range.max(),
+                    Step::Previous => range.min(),
+                };
+                quote! {
+                    if *#binding == #limit {
+                        None
+                    } else {
+                        Enumerable::#method(#binding)
+                    }
+                }
+            }
+            None => quote! {Enumerable::#method(#binding)},
+        }
+    }
+}
+
+/// The parsed fields of a struct or the parsed variants of an enum
+enum Shape<'a> {
+    Struct(BoundedFields<'a>),
+    Enum(BoundedVariants<'a>),
+}
+
+pub fn impl_enumerable_trait_derive(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let ident = &ast.ident;
     let span = ident.span();
 
-    let next_impl = impl_next_for_ast(ast);
+    attributes::validate_container(&ast.attrs)?;
+    let shape = match &ast.data {
+        Enum(data) => {
+            let variants = BoundedVariants::parse(data)?;
+            if variants.list.is_empty() {
+                return Err(super::no_variants_error(
+                    ast,
+                    "Enumerable",
+                    variants.any_skipped,
+                ));
+            }
+            Shape::Enum(variants)
+        }
+        Struct(data) => Shape::Struct(BoundedFields::parse(&data.fields)?),
+        Union(data) => return Err(union_error(data)),
+    };
+
+    let next_impl = impl_step_for_shape(&shape, Step::Next);
+    let previous_impl = impl_step_for_shape(&shape, Step::Previous);
+    let index::IndexImpl {
+        count,
+        to_index,
+        from_index,
+    } = match &shape {
+        Shape::Struct(fields) => index::impl_index_for_struct(fields),
+        Shape::Enum(variants) => index::impl_index_for_enum(variants),
+    };
+
+    let generics = super::add_trait_bounds(
+        &ast.generics,
+        quote! {derive_macros_helpers::Bounded + derive_macros_helpers::Enumerable + Clone},
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let result = quote_spanned! {span=>
 
-        impl Enumerable for #ident {
+        impl #impl_generics Enumerable for #ident #ty_generics #where_clause {
+
+            const COUNT: Option<usize> = #count;
 
             fn next(&self) -> Option<Self>{
                 #next_impl
             }
 
+            fn previous(&self) -> Option<Self>{
+                #previous_impl
+            }
+
+            fn try_to_index(&self) -> Option<usize> {
+                #to_index
+            }
+
+            fn from_index(__index: usize) -> Option<Self> {
+                #from_index
+            }
+
         }
 
     };
-    result.into()
+    Ok(result)
+}
+
+fn union_error(data: &DataUnion) -> syn::Error {
+    syn::Error::new_spanned(data.union_token, "Enumerable can not be derived for unions")
 }
 
-fn impl_next_for_ast(ast: &DeriveInput) -> TokenStream {
-    match &ast.data {
-        Enum(data) => impl_next_for_enum(data),
-        Struct(_) => unimplemented!("Enumerable is currently not derivable for Struct Types"),
-        Union(_) => panic!("Enumerable is not derivable for Union Types"),
+fn impl_step_for_shape(shape: &Shape, step: Step) -> TokenStream {
+    match shape {
+        Shape::Struct(fields) => impl_step_for_struct(fields, step),
+        Shape::Enum(variants) => impl_step_for_enum(variants, step),
     }
 }
 
-fn impl_next_for_enum(enum_data: &DataEnum) -> TokenStream {
-    let variant_iterator: Vec<_> = enum_data
-        .variants
-        .iter()
-        .filter(|var| !has_skip_attribute(var))
-        .collect();
+fn impl_step_for_struct(fields: &BoundedFields, step: Step) -> TokenStream {
+    let constructor = quote! {Self};
+    let pattern = fields.pattern(&constructor);
+    let result = iter_fields(&constructor, fields, quote! {None}, step);
+    quote! {
+        //Deconstruct all Fields and step starting at the last field carrying if necessary
+        let #pattern = self;
+        #result
+    }
+}
+
+fn impl_step_for_enum(variants: &BoundedVariants, step: Step) -> TokenStream {
+    let mut variant_iterator: Vec<_> = variants.list.iter().collect();
+
+    //when stepping backwards the variant following the current one is the one declared before it
+    if let Step::Previous = step {
+        variant_iterator.reverse();
+    }
 
     let mut matches = vec![];
 
     let mut current = variant_iterator.as_slice();
 
     while let [current_variant, remaining_variants @ ..] = current {
-        let match_block = impl_next_for_enum_variant(current_variant, remaining_variants);
+        let match_block = impl_step_for_enum_variant(current_variant, remaining_variants, step);
         matches.push(match_block);
 
         current = remaining_variants;
     }
 
+    //skipped variants are not part of the enumeration
+    matches.extend(variants.skipped_arm(quote! {None}));
+
     let result = quote! {
         match self {
             #(#matches),*
@@ -57,100 +186,57 @@ fn impl_next_for_enum(enum_data: &DataEnum) -> TokenStream {
     result
 }
 
-fn impl_next_for_enum_variant(current_variant: &Variant, next_variant: &[&Variant]) -> TokenStream {
-    let ident = &current_variant.ident;
-
-    //minimum value for next variant if a next variant exists
-    let next_min = if let [next_variant, ..] = next_variant {
-        let min = super::bounded::impl_enum::get_min_for_enum_variant(next_variant);
-        quote! {Some(#min)}
+fn impl_step_for_enum_variant(
+    current_variant: &BoundedVariant,
+    next_variant: &[&BoundedVariant],
+    step: Step,
+) -> TokenStream {
+    //first value (in stepping direction) for next variant if a next variant exists
+    let next_reset = if let [next_variant, ..] = next_variant {
+        let reset = step.reset_variant(next_variant);
+        quote! {Some(#reset)}
     } else {
         quote! {None}
     };
 
-    match &current_variant.fields {
-        Fields::Unit => {
-            //For Unit Variant directly return next Variants first value
-            quote! {
-                Self::#ident => {
-                    #next_min
-                }
-            }
-        }
-        Fields::Named(FieldsNamed { named, .. }) => {
-            let field_names: Vec<_> = named
-                .iter()
-                .map(|field| field.ident.as_ref().unwrap())
-                .collect();
-            let result = iter_fields_named(ident, &field_names, next_min);
-            quote! {
-                //Deconstruct all Fields and increment starting at the first field carrying and rolling over to the next variant if necessary
-                Self::#ident{#(#field_names),*} => {
-                    #result
-                }
-            }
-        }
-        Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
-            let field_names_pre: Vec<Ident> = (0..unnamed.iter().len())
-                .map(|index| Ident::new(&format!("index{}", index), Span::call_site()))
-                .collect();
-
-            let field_names_pre: Vec<_> = field_names_pre.iter().collect();
-
-            let result = iter_fields_unnamed(ident, &field_names_pre, next_min);
-            quote! {
-                //Deconstruct all Fields and increment starting at the first field carrying and rolling over to the next variant if necessary
-                Self::#ident(#(#field_names_pre),*) => {
-                    #result
-                }
-            }
-        }
-    }
-}
-
-fn iter_fields_unnamed(variant_ident: &Ident, fields: &[&Ident], last: TokenStream) -> TokenStream {
-    let mut prev = last;
-
-    for i in 0..fields.len() {
-        if let (prior, [current, behind @ ..]) = fields.split_at(i) {
-            //Elements that keep their current value
-            let keep = prior.iter();
-
-            //Elements that get reset to their minimum
-            let reset = behind.iter().map(|_| quote! {Bounded::minimum()});
+    let path = current_variant.path();
+    let pattern = current_variant.fields.pattern(&path);
+    let result = iter_fields(&path, &current_variant.fields, next_reset, step);
 
-            prev = quote! {
-                if let Some(value) = Enumerable::next(#current) {
-                    Some(Self::#variant_ident(#(Clone::clone(#keep),)* value, #(#reset),*))
-                }else{
-                    #prev
-                }
-            }
-        } else {
-            // i < fields.len() is true for all iterations
-            // as such the right halve of the always contains at least one value
-            unreachable!()
+    //Deconstruct all Fields and step starting at the last field carrying and rolling over to the next variant if necessary
+    quote! {
+        #pattern => {
+            #result
         }
     }
-
-    prev
 }
 
-fn iter_fields_named(variant_ident: &Ident, fields: &[&Ident], last: TokenStream) -> TokenStream {
+fn iter_fields(
+    constructor: &TokenStream,
+    fields: &BoundedFields,
+    last: TokenStream,
+    step: Step,
+) -> TokenStream {
     let mut prev = last;
 
-    for i in 0..fields.len() {
-        if let (prior, [current, behind @ ..]) = fields.split_at(i) {
+    for i in 0..fields.list.len() {
+        if let (prior, [current, behind @ ..]) = fields.list.split_at(i) {
             //Elements that keep their current value
-            let keep = prior.iter();
-            let keep2 = keep.clone();
+            let keep = prior.iter().map(|field| {
+                let binding = &field.binding;
+                quote! {Clone::clone(#binding)}
+            });
+
+            //Elements that get reset to their minimum or maximum
+            let reset = behind.iter().map(|field| step.reset_field(field));
 
-            //Elements that get reset to their minimum
-            let reset = behind.iter();
+            let values = keep.chain(Some(quote! {value})).chain(reset);
+            let constructed = fields.construct(constructor, values);
+            let step_current = step.step_field(current);
 
             prev = quote! {
-                if let Some(value) = Enumerable::next(#current) {
-                    Some(Self::#variant_ident{#(#keep:Clone::clone(#keep2),)* #current:value, #(#reset:Bounded::minimum()),*})
+                if let Some(value) = #step_current {
+                    Some(#constructed)
                 }else{
                     #prev
                 }
@@ -164,13 +250,3 @@ fn iter_fields_named(variant_ident: &Ident, fields: &[&Ident], last: TokenStream
 
     prev
 }
-
-fn has_skip_attribute(variant: &Variant) -> bool {
-    variant.attrs.iter().any(|attr| {
-        attr.path()
-            .segments
-            .first()
-            .map(|pair| pair.ident == "skip")
-            .unwrap_or(false)
-    })
-}
@@ -0,0 +1,190 @@
+//! Generates `COUNT`, `try_to_index` and `from_index`.
+//!
+//! Values are indexed in the same order as `Enumerable::next` steps through them,
+//! i.e. variants in declaration order and fields with the first field being the most significant.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::derive_macros::fields::{BoundedField, BoundedFields, BoundedVariant, BoundedVariants};
+
+pub struct IndexImpl {
+    pub count: TokenStream,
+    pub to_index: TokenStream,
+    pub from_index: TokenStream,
+}
+
+fn field_count(field: &BoundedField) -> TokenStream {
+    let ty = field.ty;
+    match &field.range {
+        Some(range) => {
+            let count = range.count();
+            quote! {Some(#count)}
+        }
+        None => quote! {<#ty as Enumerable>::COUNT},
+    }
+}
+
+/// The index of the bound field, evaluates to an `Option<usize>`
+fn field_to_index(field: &BoundedField) -> TokenStream {
+    let binding = &field.binding;
+    match &field.range {
+        Some(range) => {
+            // computed in i128 as the range may be wider than half of the field type,
+            // values below `min` wrap around to offsets far beyond the count
+            let min = range.min_i128();
+            let count = range.count();
+            quote! {
+                usize::try_from((*#binding as i128).wrapping_sub(#min) as u128)
+                    .ok()
+                    .filter(|offset| *offset < #count)
+            }
+        }
+        None => quote! {Enumerable::try_to_index(#binding)},
+    }
+}
+
+/// The value of the field at `index`, evaluates to an `Option` of the field type
+fn field_from_index(field: &BoundedField, index: &TokenStream) -> TokenStream {
+    let ty = field.ty;
+    match &field.range {
+        Some(range) => {
+            let min = range.min_i128();
+            let count = range.count();
+            quote! {
+                (#index < #count).then(|| (#min).wrapping_add(#index as i128) as #ty)
+            }
+        }
+        None => quote! {<#ty as Enumerable>::from_index(#index)},
+    }
+}
+
+fn fields_count(fields: &BoundedFields) -> TokenStream {
+    let counts = fields.list.iter().map(field_count);
+    quote! {
+        derive_macros_helpers::count_product(&[#(#counts),*])
+    }
+}
+
+/// Combines the indices of the bound fields, evaluates to an `Option<usize>`
+fn fields_to_index(fields: &BoundedFields) -> TokenStream {
+    let counts = fields.list.iter().map(field_count);
+    let indices = fields.list.iter().map(field_to_index);
+    quote! {
+        {
+            let __index = 0usize;
+            #(
+                let __index = derive_macros_helpers::combine_index(
+                    __index,
+                    #counts,
+                    #indices?,
+                )?;
+            )*
+            Some(__index)
+        }
+    }
+}
+
+/// Splits `__index` into the field values starting at the last field,
+/// evaluates to an `Option<Self>`
+fn fields_from_index(fields: &BoundedFields, path: &TokenStream) -> TokenStream {
+    let splits = fields.list.iter().rev().map(|field| {
+        let binding = &field.binding;
+        let count = field_count(field);
+        let value = field_from_index(field, &quote! {#binding});
+        quote! {
+            let (__index, #binding) = derive_macros_helpers::split_index(__index, #count);
+            let #binding = #value?;
+        }
+    });
+    let constructor = fields.pattern(path);
+    quote! {
+        {
+            #(#splits)*
+            if __index != 0 {
+                return None;
+            }
+            Some(#constructor)
+        }
+    }
+}
+
+pub fn impl_index_for_struct(fields: &BoundedFields) -> IndexImpl {
+    let path = quote! {Self};
+    let pattern = fields.pattern(&path);
+    let to_index = fields_to_index(fields);
+
+    IndexImpl {
+        count: fields_count(fields),
+        to_index: quote! {
+            let #pattern = self;
+            #to_index
+        },
+        from_index: fields_from_index(fields, &path),
+    }
+}
+
+pub fn impl_index_for_enum(variants: &BoundedVariants) -> IndexImpl {
+    let counts: Vec<_> = variants
+        .list
+        .iter()
+        .map(|variant| fields_count(&variant.fields))
+        .collect();
+
+    let mut to_index_arms: Vec<_> = variants
+        .list
+        .iter()
+        .enumerate()
+        .map(|(position, variant)| {
+            let pattern = variant.fields.pattern(&variant.path());
+            let offset = &counts[..position];
+            let to_index = fields_to_index(&variant.fields);
+            quote! {
+                #pattern => derive_macros_helpers::count_sum(&[#(#offset),*])?.checked_add(#to_index?)
+            }
+        })
+        .collect();
+
+    //skipped variants have no index
+    to_index_arms.extend(variants.skipped_arm(quote! {None}));
+
+    let variant_refs: Vec<_> = variants.list.iter().collect();
+
+    IndexImpl {
+        count: quote! {
+            derive_macros_helpers::count_sum(&[#(#counts),*])
+        },
+        to_index: quote! {
+            match self {
+                #(#to_index_arms),*
+            }
+        },
+        from_index: from_index_for_variants(&variant_refs, &counts),
+    }
+}
+
+/// Subtracts the count of each variant from `__index` until it falls into a variant
+fn from_index_for_variants(variants: &[&BoundedVariant], counts: &[TokenStream]) -> TokenStream {
+    if let ([variant, remaining @ ..], [count, remaining_counts @ ..]) = (variants, counts) {
+        let from_index = fields_from_index(&variant.fields, &variant.path());
+        let rest = if remaining.is_empty() {
+            quote! {None}
+        } else {
+            let rest = from_index_for_variants(remaining, remaining_counts);
+            quote! {
+                let __index = __index - count;
+                #rest
+            }
+        };
+        quote! {
+            match #count {
+                Some(count) if __index >= count => {
+                    #rest
+                }
+                _ => #from_index,
+            }
+        }
+    } else {
+        quote! {None}
+    }
+}
@@ -0,0 +1,141 @@
+//! The fields of a struct or enum variant together with their parsed attributes,
+//! shared by the `Bounded` and `Enumerable` derives.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{DataEnum, Fields, Ident, Type, Variant};
+
+use super::attributes::{self, Range};
+
+pub struct BoundedField<'a> {
+    /// The name used to bind the field in patterns, `index<n>` for unnamed fields
+    pub binding: Ident,
+    pub ty: &'a Type,
+    pub range: Option<Range>,
+}
+
+impl BoundedField<'_> {
+    pub fn minimum(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.range {
+            Some(range) => range.min(),
+            None => quote! {<#ty as Bounded>::minimum()},
+        }
+    }
+
+    pub fn maximum(&self) -> TokenStream {
+        let ty = self.ty;
+        match &self.range {
+            Some(range) => range.max(),
+            None => quote! {<#ty as Bounded>::maximum()},
+        }
+    }
+}
+
+pub struct BoundedFields<'a> {
+    fields: &'a Fields,
+    pub list: Vec<BoundedField<'a>>,
+}
+
+impl<'a> BoundedFields<'a> {
+    pub fn parse(fields: &'a Fields) -> syn::Result<Self> {
+        let list = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                Ok(BoundedField {
+                    binding: match &field.ident {
+                        Some(ident) => ident.clone(),
+                        None => Ident::new(&format!("index{}", index), Span::call_site()),
+                    },
+                    ty: &field.ty,
+                    range: attributes::field_range(&field.attrs)?,
+                })
+            })
+            .collect::<syn::Result<_>>()?;
+        Ok(BoundedFields { fields, list })
+    }
+
+    /// Constructs `path` with the given field `values` in declaration order
+    pub fn construct(
+        &self,
+        path: &TokenStream,
+        values: impl IntoIterator<Item = TokenStream>,
+    ) -> TokenStream {
+        let values = values.into_iter();
+        match self.fields {
+            Fields::Unit => quote! {#path},
+            Fields::Named(_) => {
+                let names = self.list.iter().map(|field| &field.binding);
+                quote! {#path{#(#names: #values),*}}
+            }
+            Fields::Unnamed(_) => quote! {#path(#(#values),*)},
+        }
+    }
+
+    /// A pattern for `path` binding every field to its `binding`
+    pub fn pattern(&self, path: &TokenStream) -> TokenStream {
+        let bindings = self.list.iter().map(|field| &field.binding);
+        match self.fields {
+            Fields::Unit => quote! {#path},
+            Fields::Named(_) => quote! {#path{#(#bindings),*}},
+            Fields::Unnamed(_) => quote! {#path(#(#bindings),*)},
+        }
+    }
+
+    pub fn minimum(&self, path: &TokenStream) -> TokenStream {
+        self.construct(path, self.list.iter().map(BoundedField::minimum))
+    }
+
+    pub fn maximum(&self, path: &TokenStream) -> TokenStream {
+        self.construct(path, self.list.iter().map(BoundedField::maximum))
+    }
+}
+
+pub struct BoundedVariant<'a> {
+    pub ident: &'a Ident,
+    pub fields: BoundedFields<'a>,
+}
+
+impl BoundedVariant<'_> {
+    /// The path of the variant, `Self::<ident>`
+    pub fn path(&self) -> TokenStream {
+        let ident = self.ident;
+        quote! {Self::#ident}
+    }
+}
+
+pub struct BoundedVariants<'a> {
+    /// The variants that are not skipped in declaration order
+    pub list: Vec<BoundedVariant<'a>>,
+    pub any_skipped: bool,
+}
+
+impl<'a> BoundedVariants<'a> {
+    pub fn parse(data: &'a DataEnum) -> syn::Result<Self> {
+        let mut list = vec![];
+        let mut any_skipped = false;
+        for variant in &data.variants {
+            if attributes::is_skipped(&variant.attrs)? {
+                any_skipped = true;
+                // the fields of a skipped variant are never inspected, but should still be valid
+                BoundedFields::parse(&variant.fields)?;
+            } else {
+                list.push(parse_variant(variant)?);
+            }
+        }
+        Ok(BoundedVariants { list, any_skipped })
+    }
+
+    /// A match arm for the skipped variants, if there are any
+    pub fn skipped_arm(&self, result: TokenStream) -> Option<TokenStream> {
+        self.any_skipped.then(|| quote! {_ => #result})
+    }
+}
+
+fn parse_variant(variant: &Variant) -> syn::Result<BoundedVariant<'_>> {
+    Ok(BoundedVariant {
+        ident: &variant.ident,
+        fields: BoundedFields::parse(&variant.fields)?,
+    })
+}
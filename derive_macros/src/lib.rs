@@ -2,31 +2,45 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
 
 mod derive_macros;
 
 #[allow(clippy::let_and_return)]
-#[proc_macro_derive(Bounded, attributes(skip))]
+#[proc_macro_derive(Bounded, attributes(bounded, enumerable))]
 pub fn bounded_trait_macro_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
 
     // Build the trait implementation
-    let result = derive_macros::bounded::impl_bounded_trait_derive(&ast);
+    let result = derive_macros::bounded::impl_bounded_trait_derive(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into();
     //panic!(result.to_string());
     result
 }
 
 #[allow(clippy::let_and_return)]
-#[proc_macro_derive(Enumerable, attributes(skip))]
+#[proc_macro_derive(Enumerable, attributes(bounded, enumerable))]
 pub fn enumerable_trait_macro_derive(input: TokenStream) -> TokenStream {
     // Construct a representation of Rust code as a syntax tree
     // that we can manipulate
-    let ast = syn::parse(input).unwrap();
+    let ast = parse_macro_input!(input as DeriveInput);
 
     // Build the trait implementation
-    let result = derive_macros::enumerable::impl_enumerable_trait_derive(&ast);
+    let result = derive_macros::enumerable::impl_enumerable_trait_derive(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into();
     //panic!(result.to_string());
     result
 }
+
+#[proc_macro_derive(AssetName, attributes(asset))]
+pub fn asset_name_macro_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    derive_macros::asset_name::impl_asset_name_derive(&ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
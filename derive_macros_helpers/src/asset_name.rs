@@ -0,0 +1,78 @@
+//! Names of assets generated by `#[derive(AssetName)]`.
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::{Bounded, Enumerable};
+
+/// A type whose values name asset files, `Display` and `FromStr` are inverse to each other
+pub trait AssetName: Display + FromStr {
+    /// The names of all values in the order of [`Enumerable::enumerate_all`]
+    fn asset_names() -> Vec<String>
+    where
+        Self: Enumerable + Bounded + PartialEq,
+    {
+        Self::enumerate_all()
+            .map(|value| value.to_string())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAssetNameError {
+    name: String,
+    type_name: &'static str,
+}
+
+impl ParseAssetNameError {
+    pub fn new<T>(name: &str) -> Self {
+        ParseAssetNameError {
+            name: name.to_string(),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for ParseAssetNameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not the name of a {}", self.name, self.type_name)
+    }
+}
+
+impl Error for ParseAssetNameError {}
+
+/// Every way `input` can be split into the placeholders of a format,
+/// where `literals` are the parts of the format around the placeholders
+#[doc(hidden)]
+pub fn split_format<'a>(input: &'a str, literals: &[&str]) -> Vec<Vec<&'a str>> {
+    let Some((first, rest)) = literals.split_first() else {
+        return vec![];
+    };
+    let Some(input) = input.strip_prefix(first) else {
+        return vec![];
+    };
+    let Some(next) = rest.first() else {
+        return if input.is_empty() {
+            vec![vec![]]
+        } else {
+            vec![]
+        };
+    };
+
+    let mut splits = vec![];
+    for end in (0..=input.len()).filter(|&end| input.is_char_boundary(end)) {
+        if !input[end..].starts_with(next) {
+            continue;
+        }
+        for mut split in split_format(&input[end..], rest) {
+            split.insert(0, &input[..end]);
+            splits.push(split);
+        }
+    }
+    splits
+}
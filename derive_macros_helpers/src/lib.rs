@@ -1,3 +1,4 @@
+pub use asset_name::{AssetName, ParseAssetNameError};
 pub use bounded::Bounded;
 pub use enumerable::{Enumerable, EnumerableIterator};
 
@@ -21,11 +22,11 @@ mod bounded {
         ($a:ident) => {
             impl Bounded for $a {
                 fn minimum() -> Self {
-                    std::$a::MIN
+                    $a::MIN
                 }
 
                 fn maximum() -> Self {
-                    std::$a::MAX
+                    $a::MAX
                 }
             }
         };
@@ -41,6 +42,8 @@ mod bounded {
     impl_nums_bounded!(i64);
     impl_nums_bounded!(u128);
     impl_nums_bounded!(i128);
+    impl_nums_bounded!(usize);
+    impl_nums_bounded!(isize);
 }
 
 mod enumerable {
@@ -50,47 +53,159 @@ mod enumerable {
     where
         Self: Sized,
     {
+        /// The number of values of this type, `None` if it does not fit into a `usize`
+        const COUNT: Option<usize>;
+
         fn next(&self) -> Option<Self>;
 
+        fn previous(&self) -> Option<Self>;
+
+        /// The position of `self` in the order of [`Enumerable::enumerate_all`],
+        /// `None` if it does not fit into a `usize`
+        fn try_to_index(&self) -> Option<usize>;
+
+        /// The inverse of [`Enumerable::try_to_index`], `None` if `index` is out of range
+        fn from_index(index: usize) -> Option<Self>;
+
+        fn cardinality() -> Option<usize> {
+            Self::COUNT
+        }
+
+        /// # Panics
+        ///
+        /// If the index of `self` does not fit into a `usize`,
+        /// which is only possible if [`Enumerable::COUNT`] is `None`.
+        fn to_index(&self) -> usize {
+            self.try_to_index()
+                .expect("the index of this value does not fit into a usize")
+        }
+
         fn enumerate_all() -> EnumerableIterator<Self>
         where
             Self: Bounded,
         {
             EnumerableIterator {
-                current: Some(Self::minimum()),
+                front: Some(Self::minimum()),
+                back: Some(Self::maximum()),
             }
         }
 
         fn enumerate_following(&self) -> EnumerableIterator<Self> {
             EnumerableIterator {
-                current: self.next(),
+                front: self.next(),
+                back: None,
             }
         }
     }
 
+    /// Iterates over the values of an [`Enumerable`] from `front` up to and including `back`.
+    ///
+    /// A `back` of `None` stands for `Bounded::maximum` and is only resolved when iterating
+    /// from the back. The iterator is exhausted once `front` is `None`.
     pub struct EnumerableIterator<A> {
-        current: Option<A>,
+        front: Option<A>,
+        back: Option<A>,
+    }
+
+    impl<A> EnumerableIterator<A>
+    where
+        A: Enumerable + PartialEq,
+    {
+        /// The number of remaining values, `None` if it can not be computed without overflow
+        fn remaining(&self) -> Option<usize> {
+            let Some(front) = &self.front else {
+                return Some(0);
+            };
+            let last = match &self.back {
+                Some(back) => back == front,
+                None => front.next().is_none(),
+            };
+            if last {
+                return Some(1);
+            }
+            let end = match &self.back {
+                Some(back) => back.try_to_index()?.checked_add(1)?,
+                None => A::COUNT?,
+            };
+            end.checked_sub(front.try_to_index()?)
+        }
     }
 
     impl<A> Iterator for EnumerableIterator<A>
     where
-        A: Enumerable,
+        A: Enumerable + PartialEq,
     {
         type Item = A;
 
         fn next(&mut self) -> Option<Self::Item> {
-            if let Some(current) = self.current.take() {
-                if let Some(next) = current.next() {
-                    self.current.replace(next);
+            let current = self.front.take()?;
+            if self.back.as_ref() == Some(&current) {
+                self.back = None;
+            } else {
+                self.front = current.next();
+            }
+            Some(current)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            match self.remaining() {
+                Some(remaining) => (remaining, Some(remaining)),
+                // at least the front remains, the rest is only known to be more than fits
+                // into the indices around it
+                None => (usize::from(self.front.is_some()), None),
+            }
+        }
+
+        fn nth(&mut self, n: usize) -> Option<Self::Item> {
+            let target = self
+                .front
+                .as_ref()?
+                .try_to_index()
+                .and_then(|index| index.checked_add(n));
+
+            let Some(target) = target else {
+                for _ in 0..n {
+                    self.next()?;
                 }
-                Some(current)
+                return self.next();
+            };
+
+            // an index that does not fit into a usize is always larger than the target
+            let in_range = match &self.back {
+                Some(back) => back.try_to_index().is_none_or(|back| target <= back),
+                None => A::COUNT.is_none_or(|count| target < count),
+            };
+
+            if in_range {
+                self.front = A::from_index(target);
+                self.next()
             } else {
+                self.front = None;
+                self.back = None;
                 None
             }
         }
     }
 
+    impl<A> DoubleEndedIterator for EnumerableIterator<A>
+    where
+        A: Enumerable + Bounded + PartialEq,
+    {
+        fn next_back(&mut self) -> Option<Self::Item> {
+            let front = self.front.as_ref()?;
+            let current = self.back.take().unwrap_or_else(A::maximum);
+            if front == &current {
+                self.front = None;
+            } else {
+                self.back = current.previous();
+            }
+            Some(current)
+        }
+    }
+
     impl Enumerable for bool {
+        const COUNT: Option<usize> = Some(2);
+
         fn next(&self) -> Option<Self> {
             if *self {
                 None
@@ -98,26 +213,146 @@ mod enumerable {
                 Some(true)
             }
         }
-    }
-}
 
-macro_rules! impl_nums_enumerable {
-    ($a:ident) => {
-        impl Enumerable for $a {
-            fn next(&self) -> Option<Self> {
-                self.checked_add(1)
+        fn previous(&self) -> Option<Self> {
+            if *self {
+                Some(false)
+            } else {
+                None
+            }
+        }
+
+        fn try_to_index(&self) -> Option<usize> {
+            Some(usize::from(*self))
+        }
+
+        fn from_index(index: usize) -> Option<Self> {
+            match index {
+                0 => Some(false),
+                1 => Some(true),
+                _ => None,
             }
         }
-    };
+    }
+
+    macro_rules! impl_nums_enumerable {
+        ($a:ident, $unsigned:ident) => {
+            impl Enumerable for $a {
+                const COUNT: Option<usize> = if $a::BITS < usize::BITS {
+                    Some(1 << $a::BITS)
+                } else {
+                    None
+                };
+
+                fn next(&self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn previous(&self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn try_to_index(&self) -> Option<usize> {
+                    usize::try_from(self.abs_diff($a::MIN)).ok()
+                }
+
+                fn from_index(index: usize) -> Option<Self> {
+                    let offset = $unsigned::try_from(index).ok()?;
+                    Some($a::MIN.wrapping_add(offset as $a))
+                }
+            }
+        };
+    }
+
+    impl_nums_enumerable!(u8, u8);
+    impl_nums_enumerable!(i8, u8);
+    impl_nums_enumerable!(u16, u16);
+    impl_nums_enumerable!(i16, u16);
+    impl_nums_enumerable!(u32, u32);
+    impl_nums_enumerable!(i32, u32);
+    impl_nums_enumerable!(u64, u64);
+    impl_nums_enumerable!(i64, u64);
+    impl_nums_enumerable!(u128, u128);
+    impl_nums_enumerable!(i128, u128);
+    impl_nums_enumerable!(usize, usize);
+    impl_nums_enumerable!(isize, usize);
+}
+
+mod asset_name;
+mod std_impls;
+
+#[doc(hidden)]
+pub use asset_name::split_format;
+
+// Helpers used by the code generated by `#[derive(Enumerable)]`
+
+#[doc(hidden)]
+pub const fn count_product(counts: &[Option<usize>]) -> Option<usize> {
+    let mut product = 1usize;
+    let mut i = 0;
+    while i < counts.len() {
+        product = match counts[i] {
+            Some(count) => match product.checked_mul(count) {
+                Some(product) => product,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(product)
+}
+
+#[doc(hidden)]
+pub const fn count_sum(counts: &[Option<usize>]) -> Option<usize> {
+    let mut sum = 0usize;
+    let mut i = 0;
+    while i < counts.len() {
+        sum = match counts[i] {
+            Some(count) => match sum.checked_add(count) {
+                Some(sum) => sum,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(sum)
 }
 
-impl_nums_enumerable!(u8);
-impl_nums_enumerable!(i8);
-impl_nums_enumerable!(u16);
-impl_nums_enumerable!(i16);
-impl_nums_enumerable!(u32);
-impl_nums_enumerable!(i32);
-impl_nums_enumerable!(u64);
-impl_nums_enumerable!(i64);
-impl_nums_enumerable!(u128);
-impl_nums_enumerable!(i128);
+#[doc(hidden)]
+pub const fn count_power(count: Option<usize>, exponent: usize) -> Option<usize> {
+    let mut power = 1usize;
+    let mut i = 0;
+    while i < exponent {
+        power = match count {
+            Some(count) => match power.checked_mul(count) {
+                Some(power) => power,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(power)
+}
+
+/// Appends the `index` of a field with `count` values to the combined index `acc` of the prior fields
+#[doc(hidden)]
+pub fn combine_index(acc: usize, count: Option<usize>, index: usize) -> Option<usize> {
+    if acc == 0 {
+        Some(index)
+    } else {
+        acc.checked_mul(count?)?.checked_add(index)
+    }
+}
+
+/// Splits the index of the last field with `count` values off the combined `index`,
+/// returns the index of the remaining fields and the index of the last field
+#[doc(hidden)]
+pub fn split_index(index: usize, count: Option<usize>) -> (usize, usize) {
+    match count {
+        Some(count) => (index / count, index % count),
+        None => (0, index),
+    }
+}
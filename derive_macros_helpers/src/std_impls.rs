@@ -0,0 +1,316 @@
+//! [`Bounded`] and [`Enumerable`] for std types.
+//!
+//! The enumeration order matches the `Ord` implementation of each type
+//! and compound types carry like `#[derive(Enumerable)]`, i.e. the last element is stepped first.
+
+use crate::{combine_index, count_power, count_product, split_index, Bounded, Enumerable};
+
+impl Bounded for () {
+    fn minimum() -> Self {}
+
+    fn maximum() -> Self {}
+}
+
+impl Enumerable for () {
+    const COUNT: Option<usize> = Some(1);
+
+    fn next(&self) -> Option<Self> {
+        None
+    }
+
+    fn previous(&self) -> Option<Self> {
+        None
+    }
+
+    fn try_to_index(&self) -> Option<usize> {
+        Some(0)
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        (index == 0).then_some(())
+    }
+}
+
+/// The last code point before the surrogates `U+D800..=U+DFFF`
+const BEFORE_SURROGATES: char = '\u{D7FF}';
+/// The first code point after the surrogates `U+D800..=U+DFFF`
+const AFTER_SURROGATES: char = '\u{E000}';
+/// The number of surrogates, which are not `char`s
+const SURROGATES_LEN: u32 = AFTER_SURROGATES as u32 - BEFORE_SURROGATES as u32 - 1;
+
+impl Bounded for char {
+    fn minimum() -> Self {
+        '\0'
+    }
+
+    fn maximum() -> Self {
+        char::MAX
+    }
+}
+
+impl Enumerable for char {
+    const COUNT: Option<usize> = Some((char::MAX as u32 + 1 - SURROGATES_LEN) as usize);
+
+    fn next(&self) -> Option<Self> {
+        match *self {
+            BEFORE_SURROGATES => Some(AFTER_SURROGATES),
+            c => char::from_u32(c as u32 + 1),
+        }
+    }
+
+    fn previous(&self) -> Option<Self> {
+        match *self {
+            AFTER_SURROGATES => Some(BEFORE_SURROGATES),
+            c => char::from_u32((c as u32).checked_sub(1)?),
+        }
+    }
+
+    fn try_to_index(&self) -> Option<usize> {
+        let code_point = *self as u32;
+        let index = if *self >= AFTER_SURROGATES {
+            code_point - SURROGATES_LEN
+        } else {
+            code_point
+        };
+        usize::try_from(index).ok()
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        let index = u32::try_from(index).ok()?;
+        if index > BEFORE_SURROGATES as u32 {
+            char::from_u32(index.checked_add(SURROGATES_LEN)?)
+        } else {
+            char::from_u32(index)
+        }
+    }
+}
+
+impl<T: Bounded> Bounded for Option<T> {
+    fn minimum() -> Self {
+        None
+    }
+
+    fn maximum() -> Self {
+        Some(T::maximum())
+    }
+}
+
+impl<T: Enumerable + Bounded> Enumerable for Option<T> {
+    const COUNT: Option<usize> = match T::COUNT {
+        Some(count) => count.checked_add(1),
+        None => None,
+    };
+
+    fn next(&self) -> Option<Self> {
+        match self {
+            None => Some(Some(T::minimum())),
+            Some(value) => value.next().map(Some),
+        }
+    }
+
+    fn previous(&self) -> Option<Self> {
+        // the value preceding the minimum of `T` is `None`
+        self.as_ref().map(T::previous)
+    }
+
+    fn try_to_index(&self) -> Option<usize> {
+        match self {
+            None => Some(0),
+            Some(value) => value.try_to_index()?.checked_add(1),
+        }
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        match index.checked_sub(1) {
+            None => Some(None),
+            Some(index) => T::from_index(index).map(Some),
+        }
+    }
+}
+
+impl<A: Bounded> Bounded for (A,) {
+    fn minimum() -> Self {
+        (A::minimum(),)
+    }
+
+    fn maximum() -> Self {
+        (A::maximum(),)
+    }
+}
+
+impl<A: Enumerable> Enumerable for (A,) {
+    const COUNT: Option<usize> = A::COUNT;
+
+    fn next(&self) -> Option<Self> {
+        self.0.next().map(|a| (a,))
+    }
+
+    fn previous(&self) -> Option<Self> {
+        self.0.previous().map(|a| (a,))
+    }
+
+    fn try_to_index(&self) -> Option<usize> {
+        self.0.try_to_index()
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        A::from_index(index).map(|a| (a,))
+    }
+}
+
+impl<A: Bounded, B: Bounded> Bounded for (A, B) {
+    fn minimum() -> Self {
+        (A::minimum(), B::minimum())
+    }
+
+    fn maximum() -> Self {
+        (A::maximum(), B::maximum())
+    }
+}
+
+impl<A, B> Enumerable for (A, B)
+where
+    A: Enumerable + Bounded + Clone,
+    B: Enumerable + Bounded + Clone,
+{
+    const COUNT: Option<usize> = count_product(&[A::COUNT, B::COUNT]);
+
+    fn next(&self) -> Option<Self> {
+        if let Some(b) = self.1.next() {
+            Some((self.0.clone(), b))
+        } else {
+            self.0.next().map(|a| (a, B::minimum()))
+        }
+    }
+
+    fn previous(&self) -> Option<Self> {
+        if let Some(b) = self.1.previous() {
+            Some((self.0.clone(), b))
+        } else {
+            self.0.previous().map(|a| (a, B::maximum()))
+        }
+    }
+
+    fn try_to_index(&self) -> Option<usize> {
+        combine_index(self.0.try_to_index()?, B::COUNT, self.1.try_to_index()?)
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        let (index, b) = split_index(index, B::COUNT);
+        Some((A::from_index(index)?, B::from_index(b)?))
+    }
+}
+
+/// Larger tuples `(a, b, c, ..)` are enumerated as the pair `(a, (b, c, ..))`
+macro_rules! impl_tuple {
+    ($first_ty:ident $first:ident, $($ty:ident $value:ident),+) => {
+        impl<$first_ty: Bounded, $($ty: Bounded),+> Bounded for ($first_ty, $($ty),+) {
+            fn minimum() -> Self {
+                ($first_ty::minimum(), $($ty::minimum()),+)
+            }
+
+            fn maximum() -> Self {
+                ($first_ty::maximum(), $($ty::maximum()),+)
+            }
+        }
+
+        impl<$first_ty, $($ty),+> Enumerable for ($first_ty, $($ty),+)
+        where
+            $first_ty: Enumerable + Bounded + Clone,
+            $($ty: Enumerable + Bounded + Clone),+
+        {
+            const COUNT: Option<usize> = <($first_ty, ($($ty,)+))>::COUNT;
+
+            fn next(&self) -> Option<Self> {
+                let ($first, $($value),+) = self.clone();
+                let ($first, ($($value,)+)) = ($first, ($($value,)+)).next()?;
+                Some(($first, $($value),+))
+            }
+
+            fn previous(&self) -> Option<Self> {
+                let ($first, $($value),+) = self.clone();
+                let ($first, ($($value,)+)) = ($first, ($($value,)+)).previous()?;
+                Some(($first, $($value),+))
+            }
+
+            fn try_to_index(&self) -> Option<usize> {
+                let ($first, $($value),+) = self.clone();
+                ($first, ($($value,)+)).try_to_index()
+            }
+
+            fn from_index(index: usize) -> Option<Self> {
+                let ($first, ($($value,)+)) = <($first_ty, ($($ty,)+))>::from_index(index)?;
+                Some(($first, $($value),+))
+            }
+        }
+    };
+}
+
+impl_tuple!(A a, B b, C c);
+impl_tuple!(A a, B b, C c, D d);
+impl_tuple!(A a, B b, C c, D d, E e);
+impl_tuple!(A a, B b, C c, D d, E e, F f);
+impl_tuple!(A a, B b, C c, D d, E e, F f, G g);
+impl_tuple!(A a, B b, C c, D d, E e, F f, G g, H h);
+
+impl<T: Bounded, const N: usize> Bounded for [T; N] {
+    fn minimum() -> Self {
+        std::array::from_fn(|_| T::minimum())
+    }
+
+    fn maximum() -> Self {
+        std::array::from_fn(|_| T::maximum())
+    }
+}
+
+impl<T, const N: usize> Enumerable for [T; N]
+where
+    T: Enumerable + Bounded + Clone,
+{
+    const COUNT: Option<usize> = count_power(T::COUNT, N);
+
+    fn next(&self) -> Option<Self> {
+        let mut result = self.clone();
+        for element in result.iter_mut().rev() {
+            if let Some(value) = element.next() {
+                *element = value;
+                return Some(result);
+            }
+            *element = T::minimum();
+        }
+        None
+    }
+
+    fn previous(&self) -> Option<Self> {
+        let mut result = self.clone();
+        for element in result.iter_mut().rev() {
+            if let Some(value) = element.previous() {
+                *element = value;
+                return Some(result);
+            }
+            *element = T::maximum();
+        }
+        None
+    }
+
+    fn try_to_index(&self) -> Option<usize> {
+        self.iter().try_fold(0, |index, element| {
+            combine_index(index, T::COUNT, element.try_to_index()?)
+        })
+    }
+
+    fn from_index(mut index: usize) -> Option<Self> {
+        let mut indices = [0; N];
+        for element in indices.iter_mut().rev() {
+            (index, *element) = split_index(index, T::COUNT);
+        }
+        if index != 0 {
+            return None;
+        }
+        let values = indices.map(T::from_index);
+        values
+            .iter()
+            .all(Option::is_some)
+            .then(|| values.map(Option::unwrap))
+    }
+}
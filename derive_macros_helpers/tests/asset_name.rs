@@ -0,0 +1,74 @@
+use derive_macros::{AssetName, Bounded, Enumerable};
+use derive_macros_helpers::{AssetName, Bounded, Enumerable};
+use std::str::FromStr;
+
+#[derive(Bounded, Enumerable, AssetName, Debug, PartialEq, Clone, Copy)]
+pub enum Side {
+    #[asset(format = "l")]
+    Left,
+    #[asset(format = "r")]
+    Right,
+}
+
+#[derive(Bounded, Enumerable, AssetName, Debug, PartialEq)]
+pub enum Piece {
+    Plain,
+    TopLeft,
+    #[asset(format = "edge_{0}")]
+    Edge(Side),
+    #[asset(format = "{first}{second}")]
+    Pair {
+        first: Side,
+        second: Side,
+    },
+    #[asset(format = "lamp{lit}")]
+    Lamp {
+        #[asset(when_true = "_on", when_false = "")]
+        lit: bool,
+    },
+    #[asset(format = "{{{0}}}")]
+    Braced(bool),
+}
+
+#[derive(AssetName, Debug, PartialEq)]
+#[asset(format = "{side}_{rest}")]
+pub struct Nested {
+    side: Side,
+    rest: Piece,
+}
+
+#[test]
+fn names_follow_the_format() {
+    assert_eq!(
+        Piece::asset_names(),
+        vec![
+            "plain", "top_left", "edge_l", "edge_r", "ll", "lr", "rl", "rr", "lamp", "lamp_on",
+            "{false}", "{true}"
+        ]
+    );
+}
+
+#[test]
+fn names_parse_back_to_their_value() {
+    for piece in Piece::enumerate_all() {
+        assert_eq!(Piece::from_str(&piece.to_string()), Ok(piece));
+    }
+}
+
+#[test]
+fn placeholders_backtrack_over_separators() {
+    let nested = Nested {
+        side: Side::Right,
+        rest: Piece::TopLeft,
+    };
+    assert_eq!(nested.to_string(), "r_top_left");
+    assert_eq!("r_top_left".parse(), Ok(nested));
+}
+
+#[test]
+fn unknown_names_are_rejected() {
+    for name in ["", "edge_", "edge_x", "lamp_off", "plainx", "{maybe}"] {
+        let error = Piece::from_str(name).unwrap_err();
+        assert_eq!(error.name(), name);
+    }
+}
@@ -0,0 +1,24 @@
+use derive_macros::AssetName;
+
+#[derive(AssetName)]
+enum UnknownField {
+    #[asset(format = "{missing}")]
+    Variant { present: bool },
+}
+
+#[derive(AssetName)]
+enum MissingField {
+    #[asset(format = "{first}")]
+    Variant { first: bool, second: bool },
+}
+
+#[derive(AssetName)]
+enum NoFormat {
+    Variant(bool),
+}
+
+#[derive(AssetName)]
+#[asset(format = "b_{0}")]
+struct OnlyTrue(#[asset(when_true = "yes")] bool);
+
+fn main() {}
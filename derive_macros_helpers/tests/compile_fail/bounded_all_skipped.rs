@@ -0,0 +1,11 @@
+use derive_macros::Bounded;
+
+#[derive(Bounded)]
+enum Hidden {
+    #[enumerable(skip)]
+    First,
+    #[bounded(skip)]
+    Second,
+}
+
+fn main() {}
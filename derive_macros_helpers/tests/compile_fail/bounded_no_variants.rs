@@ -0,0 +1,6 @@
+use derive_macros::Bounded;
+
+#[derive(Bounded)]
+enum Never {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_macros::Bounded;
+
+#[derive(Bounded)]
+union Bits {
+    signed: i8,
+    unsigned: u8,
+}
+
+fn main() {}
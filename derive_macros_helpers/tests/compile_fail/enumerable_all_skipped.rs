@@ -0,0 +1,11 @@
+use derive_macros::Enumerable;
+
+#[derive(Enumerable)]
+enum Hidden {
+    #[enumerable(skip)]
+    First,
+    #[enumerable(skip)]
+    Second,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use derive_macros::Enumerable;
+
+#[derive(Enumerable)]
+enum Never {}
+
+fn main() {}
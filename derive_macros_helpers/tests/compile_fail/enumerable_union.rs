@@ -0,0 +1,9 @@
+use derive_macros::Enumerable;
+
+#[derive(Enumerable)]
+union Bits {
+    signed: i8,
+    unsigned: u8,
+}
+
+fn main() {}
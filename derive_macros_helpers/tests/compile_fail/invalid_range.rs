@@ -0,0 +1,27 @@
+use derive_macros::Bounded;
+
+#[derive(Bounded)]
+struct MissingMax {
+    #[bounded(min = 1)]
+    value: u8,
+}
+
+#[derive(Bounded)]
+struct Inverted {
+    #[bounded(min = 3, max = 1)]
+    value: u8,
+}
+
+#[derive(Bounded)]
+struct NotALiteral {
+    #[bounded(min = 0, max = u8::MAX)]
+    value: u8,
+}
+
+#[derive(Bounded)]
+#[bounded(skip)]
+struct Container {
+    value: u8,
+}
+
+fn main() {}
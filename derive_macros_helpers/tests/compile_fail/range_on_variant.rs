@@ -0,0 +1,9 @@
+use derive_macros::Enumerable;
+
+#[derive(Enumerable)]
+enum Choice {
+    #[enumerable(min = 0, max = 1)]
+    First,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_macros::Bounded;
+
+#[derive(Bounded)]
+struct Pair {
+    first: bool,
+    #[bounded(skip)]
+    second: bool,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derive_macros::{Bounded, Enumerable};
+
+#[derive(Bounded, Enumerable)]
+enum Choice {
+    #[bounded(hide)]
+    First,
+    Second,
+}
+
+fn main() {}
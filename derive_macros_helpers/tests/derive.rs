@@ -5,3 +5,319 @@ use derive_macros_helpers::{Bounded, Enumerable};
 pub enum Test {
     Variant(i8),
 }
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub struct Named {
+    first: bool,
+    second: bool,
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq)]
+pub struct Tuple(bool, u8);
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub struct Unit;
+
+#[test]
+fn named_struct_enumerates_all_values() {
+    let all: Vec<_> = Named::enumerate_all().collect();
+    assert_eq!(
+        all,
+        vec![
+            Named {
+                first: false,
+                second: false
+            },
+            Named {
+                first: false,
+                second: true
+            },
+            Named {
+                first: true,
+                second: false
+            },
+            Named {
+                first: true,
+                second: true
+            },
+        ]
+    );
+}
+
+#[test]
+fn tuple_struct_carries_into_previous_field() {
+    assert_eq!(Tuple(false, 41).next(), Some(Tuple(false, 42)));
+    assert_eq!(Tuple(false, u8::MAX).next(), Some(Tuple(true, 0)));
+    assert_eq!(Tuple(true, u8::MAX).next(), None);
+    assert_eq!(Tuple::enumerate_all().count(), 2 * 256);
+}
+
+#[test]
+fn unit_struct_has_a_single_value() {
+    assert_eq!(Unit::enumerate_all().collect::<Vec<_>>(), vec![Unit]);
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub enum Mixed {
+    First,
+    Second(bool),
+    Third { a: bool, b: bool },
+}
+
+#[test]
+fn previous_is_the_inverse_of_next() {
+    for value in Mixed::enumerate_all() {
+        if let Some(next) = value.next() {
+            assert_eq!(next.previous(), Some(value.clone()));
+        }
+    }
+    assert_eq!(Mixed::First.previous(), None);
+    assert_eq!(Mixed::Second(false).previous(), Some(Mixed::First));
+    assert_eq!(
+        Mixed::Third { a: false, b: false }.previous(),
+        Some(Mixed::Second(true))
+    );
+    assert_eq!(Tuple(true, 0).previous(), Some(Tuple(false, u8::MAX)));
+}
+
+#[test]
+fn enumerate_all_reversed() {
+    let forward: Vec<_> = Mixed::enumerate_all().collect();
+    let mut backward: Vec<_> = Mixed::enumerate_all().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(forward.len(), 7);
+}
+
+#[test]
+fn enumerate_from_both_ends_meets_in_the_middle() {
+    let mut iter = u8::enumerate_all();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(255));
+    assert_eq!(iter.by_ref().count(), 254);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = false.enumerate_following();
+    assert_eq!(iter.next_back(), Some(true));
+    assert_eq!(iter.next(), None);
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq)]
+pub struct Wide(bool, u64);
+
+#[test]
+fn count_matches_enumeration() {
+    assert_eq!(Mixed::COUNT, Some(7));
+    assert_eq!(Mixed::cardinality(), Some(Mixed::enumerate_all().count()));
+    assert_eq!(Named::COUNT, Some(4));
+    assert_eq!(Tuple::COUNT, Some(2 * 256));
+    assert_eq!(Unit::COUNT, Some(1));
+    assert_eq!(Test::COUNT, Some(256));
+}
+
+#[test]
+fn index_is_a_bijection() {
+    for (index, value) in Mixed::enumerate_all().enumerate() {
+        assert_eq!(value.to_index(), index);
+        assert_eq!(Mixed::from_index(index), Some(value));
+    }
+    for (index, value) in Tuple::enumerate_all().enumerate() {
+        assert_eq!(value.to_index(), index);
+        assert_eq!(Tuple::from_index(index), Some(value));
+    }
+    assert_eq!(Mixed::from_index(7), None);
+    assert_eq!(Tuple::from_index(2 * 256), None);
+    assert_eq!(Unit::from_index(1), None);
+}
+
+#[test]
+fn index_overflow_is_checked() {
+    assert_eq!(u64::COUNT, None);
+    assert_eq!(i128::COUNT, None);
+    assert_eq!(Wide::COUNT, None);
+    assert_eq!(Wide(false, 5).try_to_index(), Some(5));
+    assert_eq!(Wide(true, 0).try_to_index(), None);
+    assert_eq!(Wide::from_index(5), Some(Wide(false, 5)));
+    assert_eq!(i128::MAX.try_to_index(), None);
+    assert_eq!(i128::MIN.try_to_index(), Some(0));
+    assert_eq!(i128::from_index(1), Some(i128::MIN + 1));
+    assert_eq!(i8::from_index(128), Some(0));
+    assert_eq!(i8::from_index(256), None);
+}
+
+#[test]
+fn enumerable_iterator_size_hint_is_exact() {
+    let mut iter = Mixed::enumerate_all();
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    iter.next_back();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.nth(2), Some(Mixed::Second(true)));
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.nth(3), None);
+    assert_eq!(iter.next(), None);
+
+    assert_eq!(u8::MAX.enumerate_following().size_hint(), (0, Some(0)));
+    assert_eq!(250u8.enumerate_following().size_hint(), (5, Some(5)));
+    assert_eq!(u64::enumerate_all().nth(1 << 40), Some(1 << 40));
+}
+
+#[test]
+fn enumerable_iterator_size_hint_without_indices_is_a_lower_bound() {
+    assert_eq!(
+        (u128::MAX - 1).enumerate_following().size_hint(),
+        (1, Some(1))
+    );
+    assert_eq!((u128::MAX - 3).enumerate_following().size_hint(), (1, None));
+    assert_eq!(u128::enumerate_all().size_hint(), (1, None));
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub enum Slot<T> {
+    Empty,
+    Full(T),
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq)]
+pub struct Pair<A, B: PartialEq> {
+    first: A,
+    second: B,
+}
+
+#[test]
+fn generic_enum_enumerates_its_parameter() {
+    assert_eq!(
+        Slot::<bool>::enumerate_all().collect::<Vec<_>>(),
+        vec![Slot::Empty, Slot::Full(false), Slot::Full(true)]
+    );
+    assert_eq!(Slot::<Slot<bool>>::COUNT, Some(4));
+    assert_eq!(Slot::Full(Named::maximum()).to_index(), 4);
+    assert_eq!(Slot::<Unit>::from_index(1), Some(Slot::Full(Unit)));
+}
+
+#[test]
+fn generic_struct_enumerates_all_combinations() {
+    let all: Vec<_> = Pair::<bool, Unit>::enumerate_all().rev().collect();
+    assert_eq!(
+        all,
+        vec![
+            Pair {
+                first: true,
+                second: Unit
+            },
+            Pair {
+                first: false,
+                second: Unit
+            },
+        ]
+    );
+    assert_eq!(Pair::<u8, bool>::COUNT, Some(512));
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub enum Partial {
+    #[enumerable(skip)]
+    Hidden(u64),
+    Shown,
+    #[bounded(skip)]
+    Secret,
+    Last(bool),
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub struct Lamp {
+    on: bool,
+    #[bounded(min = 0, max = 3)]
+    brightness: u8,
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub enum Offset {
+    By(#[enumerable(min = -2, max = 2)] i8),
+}
+
+#[derive(Bounded, Enumerable, Debug, PartialEq, Clone)]
+pub struct Temperature {
+    #[enumerable(min = -100, max = 100)]
+    value: i8,
+}
+
+#[test]
+fn skipped_variants_are_not_enumerated() {
+    assert_eq!(Partial::minimum(), Partial::Shown);
+    assert_eq!(
+        Partial::enumerate_all().collect::<Vec<_>>(),
+        vec![Partial::Shown, Partial::Last(false), Partial::Last(true)]
+    );
+    assert_eq!(Partial::COUNT, Some(3));
+    assert_eq!(Partial::Secret.next(), None);
+    assert_eq!(Partial::Hidden(3).try_to_index(), None);
+}
+
+#[test]
+fn field_ranges_restrict_enumeration() {
+    assert_eq!(
+        Lamp::minimum(),
+        Lamp {
+            on: false,
+            brightness: 0
+        }
+    );
+    assert_eq!(
+        Lamp::maximum(),
+        Lamp {
+            on: true,
+            brightness: 3
+        }
+    );
+    assert_eq!(Lamp::COUNT, Some(8));
+    let all: Vec<_> = Lamp::enumerate_all().collect();
+    assert_eq!(all.len(), 8);
+    for (index, lamp) in all.iter().enumerate() {
+        assert!(lamp.brightness <= 3);
+        assert_eq!(lamp.to_index(), index);
+        assert_eq!(Lamp::from_index(index).as_ref(), Some(lamp));
+    }
+    assert_eq!(
+        Lamp {
+            on: true,
+            brightness: 0
+        }
+        .previous(),
+        Some(Lamp {
+            on: false,
+            brightness: 3
+        })
+    );
+    assert_eq!(
+        Lamp {
+            on: false,
+            brightness: 200
+        }
+        .try_to_index(),
+        None
+    );
+
+    assert_eq!(
+        Offset::enumerate_all().collect::<Vec<_>>(),
+        (-2..=2).map(Offset::By).collect::<Vec<_>>()
+    );
+    assert_eq!(Offset::By(0).to_index(), 2);
+}
+
+#[test]
+fn field_ranges_wider_than_half_the_type_are_indexed() {
+    assert_eq!(Temperature::COUNT, Some(201));
+    assert_eq!(Temperature { value: 50 }.try_to_index(), Some(150));
+    assert_eq!(
+        Temperature::from_index(150),
+        Some(Temperature { value: 50 })
+    );
+    assert_eq!(Temperature { value: 101 }.try_to_index(), None);
+    assert_eq!(Temperature { value: -101 }.try_to_index(), None);
+    assert_eq!(Temperature::from_index(201), None);
+    for (index, wide) in Temperature::enumerate_all().enumerate() {
+        assert_eq!(wide.try_to_index(), Some(index));
+        assert_eq!(Temperature::from_index(index), Some(wide));
+    }
+}
@@ -0,0 +1,100 @@
+use derive_macros::{Bounded, Enumerable};
+use derive_macros_helpers::{Bounded, Enumerable};
+use std::fmt::Debug;
+
+/// Enumerates all values of `T` and checks them against `Ord` and the index bijection
+fn assert_exhaustive_and_ordered<T>()
+where
+    T: Enumerable + Bounded + Ord + Debug + Clone,
+{
+    let all: Vec<T> = T::enumerate_all().collect();
+
+    assert_eq!(Some(all.len()), T::COUNT);
+    assert_eq!(all.first(), Some(&T::minimum()));
+    assert_eq!(all.last(), Some(&T::maximum()));
+    for pair in all.windows(2) {
+        assert!(
+            pair[0] < pair[1],
+            "{:?} is not less than {:?}",
+            pair[0],
+            pair[1]
+        );
+        assert_eq!(pair[1].previous().as_ref(), Some(&pair[0]));
+    }
+    for (index, value) in all.iter().enumerate() {
+        assert_eq!(value.to_index(), index);
+        assert_eq!(T::from_index(index).as_ref(), Some(value));
+    }
+    assert_eq!(T::from_index(all.len()), None);
+
+    let mut reversed: Vec<T> = T::enumerate_all().rev().collect();
+    reversed.reverse();
+    assert_eq!(all, reversed);
+}
+
+#[derive(Bounded, Enumerable, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Bounded, Enumerable, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Compound {
+    facing: Option<Direction>,
+    flags: (bool, bool),
+    connected: [bool; 4],
+    nothing: (),
+}
+
+#[test]
+fn unit_is_exhaustive() {
+    assert_exhaustive_and_ordered::<()>();
+}
+
+#[test]
+fn option_is_exhaustive() {
+    assert_exhaustive_and_ordered::<Option<Direction>>();
+    assert_exhaustive_and_ordered::<Option<Option<bool>>>();
+    assert_eq!(Option::<u64>::COUNT, None);
+}
+
+#[test]
+fn tuples_are_exhaustive() {
+    assert_exhaustive_and_ordered::<(bool,)>();
+    assert_exhaustive_and_ordered::<(bool, bool)>();
+    assert_exhaustive_and_ordered::<(Direction, bool, u8)>();
+    assert_exhaustive_and_ordered::<(bool, bool, bool, bool, bool, bool, bool, bool)>();
+}
+
+#[test]
+fn arrays_are_exhaustive() {
+    assert_exhaustive_and_ordered::<[bool; 0]>();
+    assert_exhaustive_and_ordered::<[bool; 4]>();
+    assert_exhaustive_and_ordered::<[Direction; 3]>();
+    assert_eq!(<[u32; 2]>::COUNT, None);
+}
+
+#[test]
+fn chars_skip_surrogates() {
+    assert_exhaustive_and_ordered::<char>();
+    assert_eq!('\u{D7FF}'.next(), Some('\u{E000}'));
+    assert_eq!('\u{E000}'.previous(), Some('\u{D7FF}'));
+    assert_eq!(char::MAX.next(), None);
+}
+
+#[test]
+fn pointer_sized_integers_are_enumerable() {
+    assert_eq!(usize::minimum(), 0);
+    assert_eq!(isize::MIN.to_index(), 0);
+    assert_eq!(isize::from_index(1), Some(isize::MIN + 1));
+    assert_eq!(usize::MAX.next(), None);
+    assert_eq!(usize::COUNT, None);
+}
+
+#[test]
+fn derived_types_can_contain_std_types() {
+    assert_exhaustive_and_ordered::<Compound>();
+    assert_eq!(Compound::COUNT, Some(5 * 4 * 16));
+}
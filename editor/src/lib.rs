@@ -1,4 +1,4 @@
-use derive_macros::{Bounded, Enumerable};
+use derive_macros::{AssetName, Bounded, Enumerable};
 use derive_macros_helpers::{Bounded, Enumerable};
 use learning_conrod_core::gui::{load_textures, Application, TextureMap};
 use learning_conrod_game::game::color::{IN_GAME_BACKGROUND, MENU_BACKGROUND};
@@ -8,9 +8,11 @@ use nannou::prelude::*;
 use nannou_egui::Egui;
 use std::path::PathBuf;
 
-#[derive(Enumerable, Bounded, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Enumerable, Bounded, AssetName, Ord, PartialOrd, Eq, PartialEq, Debug)]
 enum EditorTextureIndex {
+    #[asset(format = "{0}")]
     GameTile(TileTextureIndex),
+    #[asset(format = "editor_map_center")]
     MapCenter,
 }
 
@@ -20,15 +22,6 @@ impl From<TileTextureIndex> for EditorTextureIndex {
     }
 }
 
-impl ToString for EditorTextureIndex {
-    fn to_string(&self) -> String {
-        match self {
-            EditorTextureIndex::GameTile(tile) => tile.to_string(),
-            EditorTextureIndex::MapCenter => "editor_map_center".to_string(),
-        }
-    }
-}
-
 pub struct EditorApp {
     _texture_map: TextureMap<EditorTextureIndex>,
     state: EditorState,
@@ -38,7 +31,7 @@ pub enum EditorState {
     MainMenu,
     CreateLevel,
     LoadLevel(Vec<(LevelTemplate, PathBuf)>),
-    Editor(Editor, Option<GameApp>),
+    Editor(Editor, Option<Box<GameApp>>),
 }
 
 pub struct Editor {
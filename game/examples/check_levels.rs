@@ -0,0 +1,66 @@
+//! Checks that every level in `assets/levels`, or the level files given as arguments, can be won.
+//! Exits with a failure if a level can not be loaded or won, or is too large to check
+//! unless `--allow-unknown` is given.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::rc::Rc;
+
+use learning_conrod_core::get_asset_path;
+use learning_conrod_game::game::level::loading::{load_level, LoadingError};
+use learning_conrod_game::game::solver::{solve, Solution};
+use learning_conrod_game::game::GameState;
+
+fn main() -> std::io::Result<ExitCode> {
+    let (flags, arguments): (Vec<_>, Vec<_>) = std::env::args_os()
+        .skip(1)
+        .partition(|argument| argument == "--allow-unknown");
+    let allow_unknown = !flags.is_empty();
+    let mut paths: Vec<PathBuf> = arguments.into_iter().map(PathBuf::from).collect();
+    if paths.is_empty() {
+        for entry in get_asset_path().join("levels").read_dir()? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == "ron") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+    }
+
+    let mut failed = false;
+    for path in &paths {
+        let level = match load_level(path) {
+            Ok(level) => level,
+            Err(LoadingError::Invalid(err)) => {
+                println!("{}: invalid, {}", path.display(), err);
+                failed = true;
+                continue;
+            }
+            Err(_) => {
+                println!("{}: could not be loaded", path.display());
+                failed = true;
+                continue;
+            }
+        };
+        let state = GameState::new(Rc::new(level)).expect("loaded levels are valid");
+        match solve(&state) {
+            Solution::Solved(path_through) => {
+                println!("{}: solved in {} steps", path.display(), path_through.len())
+            }
+            Solution::Unsolvable => {
+                println!("{}: unsolvable", path.display());
+                failed = true;
+            }
+            Solution::GaveUp => {
+                println!("{}: too large to check", path.display());
+                failed |= !allow_unknown;
+            }
+        }
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
@@ -0,0 +1,18 @@
+//! Lists the tile each texture in `assets/textures` is used for and the textures no tile uses.
+
+use learning_conrod_core::get_asset_path;
+use learning_conrod_core::textures::identify_textures;
+use learning_conrod_game::game::TileTextureIndex;
+
+fn main() -> std::io::Result<()> {
+    let textures = identify_textures::<TileTextureIndex>(&get_asset_path().join("textures"))?;
+
+    for (name, index) in &textures.identified {
+        println!("{name}.png: {index:?}");
+    }
+    for name in &textures.orphans {
+        println!("{name}.png: orphan");
+    }
+
+    Ok(())
+}
@@ -1,26 +1,89 @@
+pub use collision::PLAYER_EXTENT;
 use color::PLAYER_COLOR;
+use derive_macros::Bounded;
+pub use event::{EventLog, GameEvent, Subscriber};
+pub use grid::Tween;
+pub use hint::{find_hint, Hint, HintSearch};
+pub use history::{History, MAX_HISTORY};
+pub use level::validation::ValidationError;
 pub use level::*;
 use nannou::prelude::*;
 use nannou_egui::Egui;
+use replay::Replay;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::rc::Rc;
 
 use learning_conrod_core::gui::TextureMap;
 use log::trace;
 
+mod collision;
 pub mod color;
+mod event;
+mod grid;
+mod hint;
+mod history;
 pub mod level;
+pub mod replay;
+pub mod solver;
 pub mod test_level;
+pub mod wiring;
+
+/// The movement the player can hold down
+#[derive(Bounded, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Action {
+    /// The direction of the action as a unit offset
+    fn offset(self) -> (f32, f32) {
+        match self {
+            Action::Up => (0.0, -1.0),
+            Action::Down => (0.0, 1.0),
+            Action::Left => (-1.0, 0.0),
+            Action::Right => (1.0, 0.0),
+        }
+    }
+
+    pub fn direction(self) -> Direction {
+        match self {
+            Action::Up => Direction::NORTH,
+            Action::Down => Direction::SOUTH,
+            Action::Left => Direction::WEST,
+            Action::Right => Direction::EAST,
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct PlayerCoordinate {
     pub x: f32,
     pub y: f32,
+    /// The floor the player is on
+    pub z: i64,
+}
+
+/// How a level was completed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    /// In seconds
+    pub time: f32,
+    pub moves: u32,
+    pub hints: u32,
 }
 
+// the level is played far more often than won, boxing its state would not save anything
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
 pub enum GameState {
     Won {
         level_template: Rc<level::LevelTemplate>,
+        stats: Stats,
+        replay: Replay,
     },
     GameState {
         //current angle of the rotating square
@@ -31,6 +94,24 @@ pub enum GameState {
         //x and y offset of the rotating square
         position: PlayerCoordinate,
         old_position: ObjectCoordinate,
+        //the direction of the last movement
+        facing: Direction,
+        //the move in progress in grid movement
+        tween: Option<Tween>,
+        //the number of tiles stepped on
+        moves: u32,
+        //the seconds played
+        time: f32,
+        //the number of hints requested
+        hints: u32,
+        //the hint shown until the player moves
+        hint: Option<Hint>,
+        //the steps that can be undone and redone
+        history: History,
+        //the events not yet processed
+        events: VecDeque<GameEvent>,
+        //the inputs so far
+        replay: Replay,
 
         //current level
         level_template: Rc<level::LevelTemplate>,
@@ -43,6 +124,7 @@ impl From<&PlayerCoordinate> for ObjectCoordinate {
         ObjectCoordinate {
             x: player.x.round() as i64,
             y: player.y.round() as i64,
+            z: player.z,
         }
     }
 }
@@ -53,39 +135,198 @@ impl From<&mut PlayerCoordinate> for ObjectCoordinate {
     }
 }
 
+impl From<ObjectCoordinate> for PlayerCoordinate {
+    fn from(coord: ObjectCoordinate) -> Self {
+        PlayerCoordinate {
+            x: coord.x as f32,
+            y: coord.y as f32,
+            z: coord.z,
+        }
+    }
+}
+
 impl GameState {
-    pub fn new(level: Rc<level::LevelTemplate>) -> GameState {
-        GameState::GameState {
+    /// Starts the level with the player on its `Start` tile
+    pub fn new(level: Rc<level::LevelTemplate>) -> Result<GameState, ValidationError> {
+        let spawn = level.spawn()?;
+        let mut level_state = level.init_state.clone();
+        level_state.reveal_around(spawn);
+        Ok(GameState::GameState {
             // Rotation for the square.
             rotation: 0.0,
             show_hud: true,
-            position: PlayerCoordinate { x: 0.0, y: 0.0 },
-            old_position: ObjectCoordinate { x: 0, y: 0 },
+            position: spawn.into(),
+            old_position: spawn,
+            facing: level.spawn_facing,
+            tween: None,
+            moves: 0,
+            time: 0.0,
+            hints: 0,
+            hint: None,
+            history: History::default(),
+            events: VecDeque::new(),
+            replay: Replay::new(&level),
 
-            level_state: level.init_state.clone(),
+            level_state,
             level_template: level,
+        })
+    }
+
+    /// Starts the level again from the beginning
+    pub fn retry(&self) -> Result<GameState, ValidationError> {
+        match self {
+            GameState::Won { level_template, .. } | GameState::GameState { level_template, .. } => {
+                GameState::new(level_template.clone())
+            }
+        }
+    }
+
+    /// The inputs recorded so far
+    pub fn replay(&self) -> &Replay {
+        match self {
+            GameState::Won { replay, .. } | GameState::GameState { replay, .. } => replay,
+        }
+    }
+
+    /// Ends the level with the current stats
+    fn win(&mut self) {
+        if let GameState::GameState {
+            level_template,
+            moves,
+            time,
+            hints,
+            replay,
+            ..
+        } = self
+        {
+            *self = GameState::Won {
+                level_template: level_template.clone(),
+                stats: Stats {
+                    time: *time,
+                    moves: *moves,
+                    hints: *hints,
+                },
+                replay: std::mem::take(replay),
+            }
         }
     }
 
-    pub fn handle_input(&mut self) {
+    /// Moves the player by `dx` and `dy` tiles without entering solid tiles
+    pub fn move_player(&mut self, dx: f32, dy: f32) {
         if let GameState::GameState {
             position,
-            old_position,
             level_state,
             ..
         } = self
+        {
+            level_state.move_player(position, dx, dy);
+        }
+    }
+
+    /// Advances the game by `dt` seconds while the `inputs` are held down,
+    /// each held action should be given once. Returns the events that happened during the tick.
+    pub fn tick(&mut self, dt: f32, inputs: &[Action]) -> Vec<GameEvent> {
+        let GameState::GameState {
+            level_template,
+            facing,
+            time,
+            replay,
+            ..
+        } = self
+        else {
+            return vec![];
+        };
+        replay.record_tick(inputs);
+        *time += dt;
+        if let Some(action) = inputs.first() {
+            *facing = action.direction();
+        }
+        match level_template.movement {
+            MovementMode::Continuous => {
+                let distance = level_template.player_speed * dt;
+                let (dx, dy) = inputs
+                    .iter()
+                    .map(|action| action.offset())
+                    .fold((0.0, 0.0), |(dx, dy), (x, y)| (dx + x, dy + y));
+                self.move_player(dx * distance, dy * distance);
+                self.handle_input();
+            }
+            MovementMode::Grid => self.tick_grid(dt, inputs),
+        }
+        if let GameState::GameState {
+            level_state,
+            events,
+            ..
+        } = self
+        {
+            events.extend(level_state.update_timers(dt));
+        }
+        self.process_events_and_wiring()
+    }
+
+    fn handle_input(&mut self) {
+        if let GameState::GameState {
+            position,
+            old_position,
+            ..
+        } = self
         {
             let new_pos: ObjectCoordinate = position.into();
             if *old_position != new_pos {
-                *old_position = new_pos;
-                trace! {"Stepping on {:?} with {:?}", old_position, position}
-                if let Some(fun) = level_state
-                    .tile_map
-                    .get_mut(old_position)
-                    .and_then(TileType::step_on)
-                {
-                    fun(self);
-                }
+                self.save_snapshot();
+                self.enter_tile(new_pos);
+            }
+        }
+    }
+
+    /// Moves the player off its current tile onto the tile at `coord`
+    fn enter_tile(&mut self, coord: ObjectCoordinate) {
+        if let GameState::GameState {
+            position,
+            old_position,
+            moves,
+            hint,
+            ..
+        } = self
+        {
+            let from = std::mem::replace(old_position, coord);
+            *moves += 1;
+            *hint = None;
+            trace! {"Stepping on {:?} with {:?}", coord, position}
+            self.queue_event(GameEvent::PlayerMoved { from, to: coord });
+        }
+    }
+
+    /// Climbs from the `ladder` the player stands on to the floor above or below.
+    /// The player ends up centered on the tile it climbed to and keeps climbing
+    /// in the same direction instead of turning back.
+    fn climb(&mut self, ladder: ObjectCoordinate) {
+        if let GameState::GameState {
+            position,
+            old_position,
+            facing,
+            level_state,
+            ..
+        } = self
+        {
+            if *old_position != ladder {
+                return;
+            }
+            let directions: &[Direction] = match facing {
+                Direction::UP => &[Direction::UP],
+                Direction::DOWN => &[Direction::DOWN],
+                _ => &[Direction::UP, Direction::DOWN],
+            };
+            let from = *old_position;
+            if let Some(&direction) = directions
+                .iter()
+                .find(|&&direction| level_state.can_move(&from, direction))
+            {
+                *facing = direction;
+                let to = from.neighbour(direction);
+                *position = to.into();
+                trace! {"Climbing from {:?} to {:?}", from, to}
+                self.enter_tile(to);
             }
         }
     }
@@ -98,18 +339,25 @@ impl GameState {
         texture_map: &TextureMap<TileTextureIndex>,
     ) {
         match self {
-            GameState::GameState { level_state, .. } => {
+            GameState::GameState {
+                level_state,
+                position,
+                ..
+            } => {
                 let draw = app.draw();
 
                 for (coord, tile) in &level_state.tile_map {
-                    tile.draw_tile(&draw, texture_map, coord, self);
+                    if coord.z == position.z {
+                        tile.draw_tile(&draw, texture_map, coord, self);
+                    }
                 }
 
+                self.draw_hint(&draw);
                 self.draw_player(&draw, texture_map);
 
                 draw.to_frame(app, frame).unwrap();
             }
-            GameState::Won { level_template: _ } => {
+            GameState::Won { .. } => {
                 egui.draw_to_frame(frame).unwrap();
             }
         }
@@ -119,7 +367,7 @@ impl GameState {
         if let GameState::GameState { rotation, .. } = self {
             draw.rect()
                 .rotate(*rotation)
-                .x_y(-PLAYER_SIZE / 2.0, -PLAYER_SIZE / 2.0)
+                .x_y(-TILE_SIZE / 2.0, -TILE_SIZE / 2.0)
                 .w_h(PLAYER_SIZE, PLAYER_SIZE)
                 .color(PLAYER_COLOR);
         }
@@ -128,3 +376,5 @@ impl GameState {
 
 pub const TILE_SIZE: f32 = 64.0;
 pub const PLAYER_SIZE: f32 = 45.0;
+/// The length of a simulation step in seconds
+pub const TICK: f32 = 1.0 / 60.0;
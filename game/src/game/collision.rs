@@ -0,0 +1,115 @@
+//! Axis separated collision of the player with the tiles of a level.
+//!
+//! Positions are in tiles, the tile at `(x, y)` covers `x - 0.5..x + 0.5` and `y - 0.5..y + 0.5`
+//! and the player is a square of `PLAYER_SIZE` centered on its position, only colliding with its floor.
+//! The player is stopped flush against the edge between two tiles
+//! if it may not move across that edge, see [`LevelState::can_move`].
+
+use std::ops::RangeInclusive;
+
+use super::{
+    Direction, LevelState, ObjectCoordinate, PlayerCoordinate, TileType, PLAYER_SIZE, TILE_SIZE,
+};
+
+/// Half the edge length of the player in tiles
+pub const PLAYER_EXTENT: f32 = PLAYER_SIZE / TILE_SIZE / 2.0;
+
+/// The largest distance moved at once, less than a tile so that no tile can be skipped
+const MAX_STEP: f32 = 0.5;
+
+/// The tiles along one axis overlapped by the player centered at `center`,
+/// a player touching the edge of a tile does not overlap it
+fn overlapped(center: f32) -> RangeInclusive<i64> {
+    let first = (center - PLAYER_EXTENT - 0.5).floor() as i64 + 1;
+    let last = (center + PLAYER_EXTENT + 0.5).ceil() as i64 - 1;
+    first..=last
+}
+
+fn overlapped_tiles(position: &PlayerCoordinate) -> impl Iterator<Item = ObjectCoordinate> {
+    let columns = overlapped(position.x);
+    let z = position.z;
+    overlapped(position.y)
+        .flat_map(move |y| columns.clone().map(move |x| ObjectCoordinate { x, y, z }))
+}
+
+impl LevelState {
+    /// Whether the tile at `coord` blocks the player, tiles outside of the `tile_map` do
+    pub fn is_solid(&self, coord: &ObjectCoordinate) -> bool {
+        self.tile_map.get(coord).is_none_or(TileType::is_solid)
+    }
+
+    /// Whether the player may move from the tile at `from` to its neighbour in `direction`,
+    /// tiles outside of the `tile_map` can not be entered
+    pub fn can_move(&self, from: &ObjectCoordinate, direction: Direction) -> bool {
+        let leave = self
+            .tile_map
+            .get(from)
+            .is_none_or(|tile| tile.can_leave(direction));
+        let enter = self
+            .tile_map
+            .get(&from.neighbour(direction))
+            .is_some_and(|tile| tile.can_enter(direction.inverted()));
+        leave && enter
+    }
+
+    /// Moves the player by `dx` and `dy` tiles, first along x then along y.
+    /// Movement along an axis stops at the first edge in the way that may not be crossed.
+    pub fn move_player(&self, position: &mut PlayerCoordinate, dx: f32, dy: f32) {
+        let east_west = if dx > 0.0 {
+            Direction::EAST
+        } else {
+            Direction::WEST
+        };
+        self.move_along(position, dx, east_west, |position| &mut position.x);
+        let north_south = if dy > 0.0 {
+            Direction::SOUTH
+        } else {
+            Direction::NORTH
+        };
+        self.move_along(position, dy, north_south, |position| &mut position.y);
+    }
+
+    /// Whether moving from `from` to `to` in `direction` crosses an edge that may not be crossed
+    fn blocked(
+        &self,
+        from: &PlayerCoordinate,
+        to: &PlayerCoordinate,
+        direction: Direction,
+    ) -> bool {
+        let before: Vec<_> = overlapped_tiles(from).collect();
+        overlapped_tiles(to)
+            .filter(|tile| !before.contains(tile))
+            .any(|tile| !self.can_move(&tile.neighbour(direction.inverted()), direction))
+    }
+
+    fn move_along(
+        &self,
+        position: &mut PlayerCoordinate,
+        delta: f32,
+        direction: Direction,
+        axis: fn(&mut PlayerCoordinate) -> &mut f32,
+    ) {
+        if delta == 0.0 {
+            return;
+        }
+        let steps = (delta.abs() / MAX_STEP).ceil();
+        let step = delta / steps;
+        for _ in 0..steps as usize {
+            let previous = position.clone();
+            *axis(position) += step;
+            if self.blocked(&previous, position, direction) {
+                let value = axis(position);
+                // the tile that may not be entered is the last one overlapped in the direction of movement
+                *value = if step > 0.0 {
+                    (*value + PLAYER_EXTENT + 0.5).ceil() - 1.5 - PLAYER_EXTENT
+                } else {
+                    (*value - PLAYER_EXTENT - 0.5).floor() + 1.5 + PLAYER_EXTENT
+                };
+                if self.blocked(&previous, position, direction) {
+                    *position = previous;
+                }
+                return;
+            }
+        }
+    }
+}
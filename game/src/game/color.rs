@@ -1,4 +1,4 @@
-use nannou::color::{BLACK, PINK, PURPLE, RED};
+use nannou::color::{BLACK, PINK, PURPLE, RED, YELLOW};
 
 pub type Color = nannou::color::Rgb8;
 
@@ -6,3 +6,4 @@ pub const MISSING_TEXTURE: Color = PURPLE;
 pub const IN_GAME_BACKGROUND: Color = BLACK;
 pub const MENU_BACKGROUND: Color = PINK;
 pub const PLAYER_COLOR: Color = RED;
+pub const HINT_COLOR: Color = YELLOW;
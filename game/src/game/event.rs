@@ -0,0 +1,149 @@
+//! Everything that happens in a level as a result of the player moving or of time passing.
+//!
+//! Events are queued and processed at the end of each tick, processing an event can queue more events,
+//! e.g. a `ButtonToggled` queues a `TargetPowered` for each target of the button.
+//! [`GameState::tick`] returns the processed events so they can be passed on to [`Subscriber`]s.
+
+use std::collections::VecDeque;
+
+use log::trace;
+use serde::{Deserialize, Serialize};
+
+use super::{GameState, ObjectCoordinate, TileType};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The player stepped off the tile at `from` onto the tile at `to`
+    PlayerMoved {
+        from: ObjectCoordinate,
+        to: ObjectCoordinate,
+    },
+    /// The power of a button, pressure plate or timed button changed, see [`TileType::power`]
+    ButtonToggled {
+        button: ObjectCoordinate,
+        power: bool,
+    },
+    /// A button or an output of the wiring switched the tile at `target`
+    TargetPowered {
+        target: ObjectCoordinate,
+        power: bool,
+    },
+    /// The player stepped onto a ladder and climbs it if possible
+    LadderEntered { ladder: ObjectCoordinate },
+    /// The player stepped onto an active goal, no events are processed afterwards
+    GoalReached { goal: ObjectCoordinate },
+}
+
+/// Observes the events of a game, e.g. to show them or play sounds
+pub trait Subscriber {
+    fn notify(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent)> Subscriber for F {
+    fn notify(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
+
+impl GameState {
+    pub(super) fn queue_event(&mut self, event: GameEvent) {
+        if let GameState::GameState { events, .. } = self {
+            events.push_back(event);
+        }
+    }
+
+    /// Processes the queued events until none are left or the level is won
+    pub(super) fn process_events(&mut self) -> Vec<GameEvent> {
+        let mut processed = vec![];
+        while let GameState::GameState { events, .. } = self {
+            let Some(event) = events.pop_front() else {
+                break;
+            };
+            trace!("Processing {:?}", event);
+            self.handle_event(&event);
+            processed.push(event);
+        }
+        processed
+    }
+
+    /// Processes the queued events, then the events of the wiring outputs they switched
+    pub(super) fn process_events_and_wiring(&mut self) -> Vec<GameEvent> {
+        let mut processed = self.process_events();
+        if let GameState::GameState {
+            level_state,
+            events,
+            ..
+        } = self
+        {
+            events.extend(level_state.update_wiring());
+        }
+        processed.extend(self.process_events());
+        processed
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) {
+        let GameState::GameState {
+            level_state,
+            events,
+            ..
+        } = self
+        else {
+            return;
+        };
+        match *event {
+            GameEvent::PlayerMoved { from, to } => {
+                let off = level_state
+                    .tile_map
+                    .get_mut(&from)
+                    .and_then(|tile| tile.step_off(from));
+                level_state.reveal_around(to);
+                let on = level_state
+                    .tile_map
+                    .get_mut(&to)
+                    .and_then(|tile| tile.step_on(to));
+                events.extend(off);
+                events.extend(on);
+            }
+            GameEvent::ButtonToggled { button, power } => {
+                if let Some(targets) = level_state
+                    .tile_map
+                    .get(&button)
+                    .and_then(TileType::targets)
+                {
+                    events.extend(
+                        targets
+                            .iter()
+                            .map(|&target| GameEvent::TargetPowered { target, power }),
+                    );
+                }
+            }
+            GameEvent::TargetPowered { target, power } => level_state.apply_power(&[target], power),
+            GameEvent::LadderEntered { ladder } => self.climb(ladder),
+            GameEvent::GoalReached { .. } => self.win(),
+        }
+    }
+}
+
+/// The most recent events, oldest first
+#[derive(Clone, Debug, Default)]
+pub struct EventLog {
+    events: VecDeque<GameEvent>,
+}
+
+impl EventLog {
+    /// The number of events kept
+    pub const CAPACITY: usize = 5;
+
+    pub fn iter(&self) -> impl Iterator<Item = &GameEvent> {
+        self.events.iter()
+    }
+}
+
+impl Subscriber for EventLog {
+    fn notify(&mut self, event: &GameEvent) {
+        if self.events.len() == Self::CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(event.clone());
+    }
+}
@@ -0,0 +1,66 @@
+//! Discrete movement by one tile per action, see [`super::MovementMode::Grid`].
+
+use super::{Action, GameState, ObjectCoordinate, PlayerCoordinate};
+
+/// A move from one tile to a neighbouring one, rendered in between the two
+#[derive(Clone, Debug)]
+pub struct Tween {
+    pub from: ObjectCoordinate,
+    pub to: ObjectCoordinate,
+    /// From `0.0` at `from` to `1.0` at `to`
+    pub progress: f32,
+}
+
+impl Tween {
+    pub fn position(&self) -> PlayerCoordinate {
+        let lerp = |from: i64, to: i64| from as f32 + (to - from) as f32 * self.progress;
+        PlayerCoordinate {
+            x: lerp(self.from.x, self.to.x),
+            y: lerp(self.from.y, self.to.y),
+            z: self.to.z,
+        }
+    }
+}
+
+impl GameState {
+    /// Continues the current move or starts a move for the first of the held `inputs`.
+    /// A move takes one tile divided by the player speed and steps on its target once done.
+    pub(super) fn tick_grid(&mut self, dt: f32, inputs: &[Action]) {
+        if let GameState::GameState {
+            position,
+            old_position,
+            tween,
+            level_template,
+            level_state,
+            ..
+        } = self
+        {
+            match tween {
+                Some(current) => {
+                    current.progress += level_template.player_speed * dt;
+                    if current.progress < 1.0 {
+                        *position = current.position();
+                    } else {
+                        let target = current.to;
+                        *tween = None;
+                        *position = target.into();
+                        self.save_snapshot();
+                        self.enter_tile(target);
+                    }
+                }
+                None => {
+                    let direction = inputs.first().map(|action| action.direction());
+                    if let Some(direction) = direction {
+                        if level_state.can_move(old_position, direction) {
+                            *tween = Some(Tween {
+                                from: *old_position,
+                                to: old_position.neighbour(direction),
+                                progress: 0.0,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
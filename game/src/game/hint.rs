@@ -0,0 +1,229 @@
+//! Hints for stuck players, based on the [`solver`](super::solver).
+//!
+//! The solver can take a while on larger levels, so [`GameState::request_hint`] searches on
+//! another thread. As [`GameState`] shares its level through an `Rc` only the parts of it
+//! needed to continue the level are sent to that thread.
+
+use std::rc::Rc;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+
+use nannou::Draw;
+
+use super::color::HINT_COLOR;
+use super::replay::Input;
+use super::solver::{solve, Solution};
+use super::{Direction, GameState, LevelState, LevelTemplate, ObjectCoordinate, TILE_SIZE};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Hint {
+    /// The next tile on the shortest way to the goal and the next button stepped on along it
+    Next {
+        tile: ObjectCoordinate,
+        button: Option<ObjectCoordinate>,
+    },
+    /// The goal can no longer be reached, steps have to be undone
+    Stuck,
+    /// No way was found, e.g. because the level is too large to search
+    Unknown,
+}
+
+/// Searches a hint for `state`, blocking until it is found
+pub fn find_hint(state: &GameState) -> Hint {
+    let GameState::GameState { old_position, .. } = state else {
+        return Hint::Unknown;
+    };
+    let path = match solve(state) {
+        Solution::Solved(path) => path,
+        Solution::Unsolvable => return Hint::Stuck,
+        Solution::GaveUp => return Hint::Unknown,
+    };
+    let Some(&first) = path.first() else {
+        return Hint::Unknown;
+    };
+
+    let mut button = None;
+    let mut next = state.clone();
+    for direction in path {
+        let GameState::GameState {
+            old_position,
+            level_state,
+            ..
+        } = &next
+        else {
+            break;
+        };
+        let to = old_position.neighbour(direction);
+        if level_state
+            .tile_map
+            .get(&to)
+            .and_then(|tile| tile.targets())
+            .is_some()
+        {
+            button = Some(to);
+            break;
+        }
+        next.step(direction);
+    }
+
+    Hint::Next {
+        tile: old_position.neighbour(first),
+        button,
+    }
+}
+
+/// The part of a game needed to search a hint, unlike a [`GameState`] it can be sent to another thread
+struct Snapshot {
+    level: LevelTemplate,
+    tile: ObjectCoordinate,
+    facing: Direction,
+    level_state: LevelState,
+}
+
+impl Snapshot {
+    fn of(state: &GameState) -> Option<Snapshot> {
+        match state {
+            GameState::GameState {
+                level_template,
+                old_position,
+                facing,
+                level_state,
+                ..
+            } => Some(Snapshot {
+                level: LevelTemplate::clone(level_template),
+                tile: *old_position,
+                facing: *facing,
+                level_state: level_state.clone(),
+            }),
+            GameState::Won { .. } => None,
+        }
+    }
+
+    fn find_hint(self) -> Hint {
+        let Ok(mut state) = GameState::new(Rc::new(self.level)) else {
+            return Hint::Unknown;
+        };
+        if let GameState::GameState {
+            position,
+            old_position,
+            facing,
+            level_state,
+            ..
+        } = &mut state
+        {
+            *position = self.tile.into();
+            *old_position = self.tile;
+            *facing = self.facing;
+            *level_state = self.level_state;
+        }
+        find_hint(&state)
+    }
+}
+
+/// A hint being searched on another thread
+#[derive(Debug)]
+pub struct HintSearch {
+    /// Where the player was when the hint was requested
+    tile: ObjectCoordinate,
+    moves: u32,
+    receiver: Receiver<Hint>,
+}
+
+impl HintSearch {
+    /// Shows the hint once it has been found, returns whether the search is over
+    pub fn poll(&self, state: &mut GameState) -> bool {
+        match self.receiver.try_recv() {
+            Ok(hint) => {
+                self.show(state, hint);
+                true
+            }
+            Err(TryRecvError::Empty) => false,
+            Err(TryRecvError::Disconnected) => true,
+        }
+    }
+
+    /// Waits for the hint and shows it
+    pub fn wait(self, state: &mut GameState) {
+        if let Ok(hint) = self.receiver.recv() {
+            self.show(state, hint);
+        }
+    }
+
+    /// Shows `hint` unless the player moved since it was requested
+    fn show(&self, state: &mut GameState, found: Hint) {
+        if let GameState::GameState {
+            old_position,
+            moves,
+            hint,
+            ..
+        } = state
+        {
+            if *old_position == self.tile && *moves == self.moves {
+                *hint = Some(found);
+            }
+        }
+    }
+}
+
+impl GameState {
+    /// Counts a hint towards the stats of the level
+    pub(super) fn count_hint(&mut self) {
+        if let GameState::GameState { hints, replay, .. } = self {
+            *hints += 1;
+            replay.record(Input::Hint);
+        }
+    }
+
+    /// Starts searching a hint for the current state, `None` once the level is won
+    pub fn request_hint(&mut self) -> Option<HintSearch> {
+        let GameState::GameState {
+            old_position: tile,
+            moves,
+            ..
+        } = *self
+        else {
+            return None;
+        };
+        let snapshot = Snapshot::of(self)?;
+        self.count_hint();
+        let (sender, receiver) = mpsc::channel();
+        // the search is dropped when the player leaves the level, nobody waits for the hint then
+        std::thread::spawn(move || sender.send(snapshot.find_hint()).ok());
+        Some(HintSearch {
+            tile,
+            moves,
+            receiver,
+        })
+    }
+
+    /// The hint currently shown
+    pub fn hint(&self) -> Option<&Hint> {
+        match self {
+            GameState::GameState { hint, .. } => hint.as_ref(),
+            GameState::Won { .. } => None,
+        }
+    }
+
+    /// Outlines the tiles of the hint on the floor the player is on
+    pub(super) fn draw_hint(&self, draw: &Draw) {
+        let GameState::GameState {
+            position,
+            hint: Some(Hint::Next { tile, button }),
+            ..
+        } = self
+        else {
+            return;
+        };
+        for coord in std::iter::once(tile).chain(button) {
+            if coord.z == position.z {
+                let x = (coord.x as f32) * TILE_SIZE - position.x * TILE_SIZE - TILE_SIZE / 2.0;
+                let y = (-coord.y as f32) * TILE_SIZE + position.y * TILE_SIZE - TILE_SIZE / 2.0;
+                draw.rect()
+                    .x_y(x, y)
+                    .w_h(TILE_SIZE, TILE_SIZE)
+                    .no_fill()
+                    .stroke_weight(4.0)
+                    .stroke(HINT_COLOR);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+//! Undo and redo of the steps of the player onto other tiles.
+//!
+//! A snapshot of the level is saved before every step, so undoing a step also undoes
+//! everything the step caused, e.g. a button toggling a gate.
+//! Undoing puts the player back onto the center of the tile it stepped off.
+
+use std::collections::VecDeque;
+
+use super::replay::Input;
+use super::{Direction, GameState, LevelState, ObjectCoordinate};
+
+/// The most steps that can be undone, older steps are forgotten
+pub const MAX_HISTORY: usize = 100;
+
+/// The state of a level in between two steps
+#[derive(Clone, Debug)]
+struct Snapshot {
+    tile: ObjectCoordinate,
+    facing: Direction,
+    moves: u32,
+    level_state: LevelState,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+}
+
+impl History {
+    /// The number of steps that can be undone
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// The number of undone steps that can be redone
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+}
+
+impl GameState {
+    /// Saves the current state before the player steps onto another tile,
+    /// the steps undone before can no longer be redone
+    pub(super) fn save_snapshot(&mut self) {
+        if let GameState::GameState {
+            old_position,
+            facing,
+            moves,
+            level_state,
+            history,
+            ..
+        } = self
+        {
+            if history.undo.len() == MAX_HISTORY {
+                history.undo.pop_front();
+            }
+            history.undo.push_back(Snapshot {
+                tile: *old_position,
+                facing: *facing,
+                moves: *moves,
+                level_state: level_state.clone(),
+            });
+            history.redo.clear();
+        }
+    }
+
+    /// Replaces the current state with `snapshot`, returns the replaced state
+    fn restore(&mut self, snapshot: Snapshot) -> Option<Snapshot> {
+        if let GameState::GameState {
+            position,
+            old_position,
+            facing,
+            tween,
+            moves,
+            hint,
+            level_state,
+            ..
+        } = self
+        {
+            let replaced = Snapshot {
+                tile: *old_position,
+                facing: *facing,
+                moves: *moves,
+                level_state: std::mem::replace(level_state, snapshot.level_state),
+            };
+            *position = snapshot.tile.into();
+            *old_position = snapshot.tile;
+            *facing = snapshot.facing;
+            *moves = snapshot.moves;
+            *tween = None;
+            *hint = None;
+            Some(replaced)
+        } else {
+            None
+        }
+    }
+
+    /// Undoes the last step, returns whether there was a step to undo
+    pub fn undo(&mut self) -> bool {
+        let GameState::GameState { history, .. } = self else {
+            return false;
+        };
+        let Some(snapshot) = history.undo.pop_back() else {
+            return false;
+        };
+        let replaced = self.restore(snapshot);
+        if let GameState::GameState {
+            history, replay, ..
+        } = self
+        {
+            history.redo.extend(replaced);
+            replay.record(Input::Undo);
+        }
+        true
+    }
+
+    /// Redoes the last undone step, returns whether there was a step to redo
+    pub fn redo(&mut self) -> bool {
+        let GameState::GameState { history, .. } = self else {
+            return false;
+        };
+        let Some(snapshot) = history.redo.pop() else {
+            return false;
+        };
+        let replaced = self.restore(snapshot);
+        if let GameState::GameState {
+            history, replay, ..
+        } = self
+        {
+            history.undo.extend(replaced);
+            replay.record(Input::Redo);
+        }
+        true
+    }
+}
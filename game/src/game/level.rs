@@ -8,29 +8,188 @@ use learning_conrod_core::gui::TextureMap;
 use log::{error, trace};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
 
 use super::color::MISSING_TEXTURE;
+use super::event::GameEvent;
+use super::wiring::Wiring;
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct LevelTemplate {
     pub name: String,
     pub init_state: LevelState,
+    /// In tiles per second
+    #[serde(default = "default_player_speed")]
+    pub player_speed: f32,
+    #[serde(default)]
+    pub movement: MovementMode,
+    /// The direction the player faces when the level starts
+    #[serde(default = "default_spawn_facing")]
+    pub spawn_facing: Direction,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+fn default_spawn_facing() -> Direction {
+    Direction::SOUTH
+}
+
+impl LevelTemplate {
+    /// The coordinate of the single `Start` tile
+    pub fn spawn(&self) -> Result<ObjectCoordinate, validation::ValidationError> {
+        let starts: Vec<_> = self
+            .init_state
+            .tile_map
+            .iter()
+            .filter(|(_, tile)| matches!(tile, TileType::Start))
+            .map(|(coord, _)| *coord)
+            .collect();
+        match starts.as_slice() {
+            [] => Err(validation::ValidationError::NoStart),
+            [start] => Ok(*start),
+            _ => Err(validation::ValidationError::MultipleStarts(starts)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), validation::ValidationError> {
+        if !(self.player_speed.is_finite() && self.player_speed > 0.0) {
+            return Err(validation::ValidationError::InvalidSpeed);
+        }
+        self.spawn()?;
+        self.init_state.validate_gates()?;
+        self.init_state.validate_timers()?;
+        self.init_state.validate_wiring()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MovementMode {
+    /// The player moves freely and collides with the tiles
+    #[default]
+    Continuous,
+    /// Each action moves the player by exactly one tile
+    Grid,
+}
+
+fn default_player_speed() -> f32 {
+    DEFAULT_PLAYER_SPEED
+}
+
+pub const DEFAULT_PLAYER_SPEED: f32 = 2.0;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct LevelState {
     pub tile_map: BTreeMap<ObjectCoordinate, TileType>,
+    #[serde(default)]
+    pub wiring: Wiring,
 }
 
+impl LevelState {
+    /// Switches the tiles at `targets` like a button with `power` would
+    pub fn apply_power(&mut self, targets: &[ObjectCoordinate], power: bool) {
+        for target in targets {
+            if let Some(tile) = self.tile_map.get_mut(target) {
+                tile.apply_button(power)
+            }
+        }
+    }
+
+    /// Reveals the hidden gates next to `coord` that are revealed by the player standing there
+    pub fn reveal_around(&mut self, coord: ObjectCoordinate) {
+        let sides = [
+            Direction::NORTH,
+            Direction::EAST,
+            Direction::SOUTH,
+            Direction::WEST,
+        ];
+        for direction in sides {
+            if let Some(TileType::Gate {
+                hidden,
+                reveal: Reveal::WhenAdjacent,
+                ..
+            }) = self.tile_map.get_mut(&coord.neighbour(direction))
+            {
+                *hidden = GateVisibility::Visible;
+            }
+        }
+    }
+
+    /// Checks that hidden gates only mimic tiles that can be mimicked
+    pub fn validate_gates(&self) -> Result<(), validation::ValidationError> {
+        for (coord, tile) in &self.tile_map {
+            if let TileType::Gate {
+                hidden: GateVisibility::Hidden(mimic),
+                ..
+            } = tile
+            {
+                if !mimic.can_be_mimicked() {
+                    return Err(validation::ValidationError::InvalidMimic(*coord));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that timed buttons count down a finite, non-negative number of seconds
+    pub fn validate_timers(&self) -> Result<(), validation::ValidationError> {
+        let valid = |seconds: f32| seconds.is_finite() && seconds >= 0.0;
+        for (coord, tile) in &self.tile_map {
+            if let TileType::TimedButton {
+                duration,
+                remaining,
+                ..
+            } = tile
+            {
+                if !valid(*duration) || !valid(*remaining) {
+                    return Err(validation::ValidationError::InvalidDuration(*coord));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts down the timed buttons by `dt` seconds, returns the events of those released
+    pub fn update_timers(&mut self, dt: f32) -> Vec<GameEvent> {
+        self.tile_map
+            .iter_mut()
+            .filter_map(|(coord, tile)| {
+                if tile.count_down(dt) {
+                    tile.toggled(*coord)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// `NORTH` is towards decreasing `y`, `EAST` towards increasing `x`
+/// and `UP` towards the floor above
 #[derive(
-    Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Debug, Serialize, Deserialize, Bounded, Enumerable,
+    Clone,
+    Copy,
+    Ord,
+    PartialOrd,
+    Eq,
+    Hash,
+    PartialEq,
+    Debug,
+    Serialize,
+    Deserialize,
+    Bounded,
+    Enumerable,
+    AssetName,
 )]
 pub enum Direction {
+    #[asset(format = "lower")]
     UP,
+    #[asset(format = "upper")]
     DOWN,
+    #[asset(format = "bottom")]
     NORTH,
+    #[asset(format = "left")]
     EAST,
+    #[asset(format = "top")]
     SOUTH,
+    #[asset(format = "right")]
     WEST,
 }
 
@@ -46,126 +205,161 @@ impl Direction {
             Direction::NORTH => SOUTH,
         }
     }
-
-    pub fn file_modifier(self) -> &'static str {
-        match self {
-            Direction::UP => "lower",
-            Direction::DOWN => "upper",
-            Direction::WEST => "right",
-            Direction::EAST => "left",
-            Direction::NORTH => "bottom",
-            Direction::SOUTH => "top",
-        }
-    }
 }
 
 #[derive(
-    Debug, Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Bounded, Enumerable,
+    Debug,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Bounded,
+    Enumerable,
+    AssetName,
 )]
 pub enum NorthSouthAxis {
+    #[asset(format = "bottom")]
     North,
+    #[asset(format = "top")]
     South,
 }
 
-impl NorthSouthAxis {
-    pub fn file_modifier(self) -> &'static str {
-        match self {
-            NorthSouthAxis::North => "bottom",
-            NorthSouthAxis::South => "top",
-        }
-    }
-}
-
 #[derive(
-    Debug, Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Bounded, Enumerable,
+    Debug,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Bounded,
+    Enumerable,
+    AssetName,
 )]
 pub enum EastWestAxis {
+    #[asset(format = "right")]
     East,
+    #[asset(format = "left")]
     West,
 }
 
-impl EastWestAxis {
-    pub fn file_modifier(self) -> &'static str {
-        match self {
-            EastWestAxis::East => "right",
-            EastWestAxis::West => "left",
-        }
-    }
-}
-
 #[derive(
-    Debug, Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Bounded, Enumerable,
+    Debug,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Bounded,
+    Enumerable,
+    AssetName,
 )]
 pub enum Orientation {
     Horizontal,
     Vertical,
 }
 
-impl Orientation {
-    pub fn file_modifier(self) -> &'static str {
-        match self {
-            Orientation::Horizontal => "horizontal",
-            Orientation::Vertical => "vertical",
-        }
-    }
-}
-
 #[derive(
-    Debug, Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, Bounded, Enumerable,
+    Debug,
+    Ord,
+    PartialOrd,
+    PartialEq,
+    Eq,
+    Hash,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Bounded,
+    Enumerable,
+    AssetName,
 )]
 pub enum WallType {
+    #[asset(format = "single_{facing}")]
     Single {
         facing: Direction,
     },
+    #[asset(format = "double_{orientation}")]
     Double {
         orientation: Orientation,
     },
+    #[asset(format = "corner_{north_south_facing}_{east_west_facing}")]
     Corner {
         north_south_facing: NorthSouthAxis,
         east_west_facing: EastWestAxis,
     },
+    #[asset(format = "inner_corner_{north_south_facing}_{east_west_facing}")]
+    InnerCorner {
+        north_south_facing: NorthSouthAxis,
+        east_west_facing: EastWestAxis,
+    },
     //primary and secondary facing should be different
+    #[asset(format = "end_{facing}")]
     End {
         facing: Direction,
     },
+    #[asset(format = "rock")]
     Lone,
     Center,
 }
 
-impl WallType {
-    pub fn file_modifier(self) -> String {
-        match self {
-            WallType::Lone => "rock".to_string(),
-            WallType::Center => "center".to_string(),
-            WallType::Single { facing } => format!("single_{}", facing.file_modifier()),
-            WallType::Double { orientation } => format!("double_{}", orientation.file_modifier()),
-            WallType::Corner {
-                north_south_facing,
-                east_west_facing,
-            } => format!(
-                "{}{}_{}",
-                if false { "inner_" } else { "" },
-                north_south_facing.file_modifier(),
-                east_west_facing.file_modifier()
-            ),
-            WallType::End { facing } => format!("end_{}", facing.file_modifier()),
-        }
-    }
-}
-
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize, Bounded, Enumerable)]
+#[derive(
+    Debug, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize, Bounded, Enumerable, AssetName,
+)]
 pub enum TileTextureIndex {
-    Wall { kind: WallType },
+    #[asset(format = "wall_{kind}")]
+    Wall {
+        kind: WallType,
+    },
     Path,
     Ladder,
     Start,
-    Goal { active: bool },
-    Gate { open: bool, facing: Direction },
-    OneWay { facing: Direction },
-    Button { pressed: bool },
+    #[asset(format = "goal{active}")]
+    Goal {
+        #[asset(when_true = "", when_false = "_inactive")]
+        active: bool,
+    },
+    // we should never need a texture for a hidden and closed gate because it is hidden
+    #[asset(format = "{open}_gate_{facing}")]
+    Gate {
+        #[asset(when_true = "open", when_false = "closed")]
+        open: bool,
+        facing: Direction,
+    },
+    #[asset(format = "one_way_{facing}")]
+    OneWay {
+        facing: Direction,
+    },
+    #[asset(format = "button{pressed}")]
+    Button {
+        #[asset(when_true = "_pressed", when_false = "")]
+        pressed: bool,
+    },
+    #[asset(format = "pressure_plate{pressed}")]
+    PressurePlate {
+        #[asset(when_true = "_pressed", when_false = "")]
+        pressed: bool,
+    },
+    #[asset(format = "timed_button{pressed}")]
+    TimedButton {
+        #[asset(when_true = "_pressed", when_false = "")]
+        pressed: bool,
+    },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TileType {
     Wall {
         kind: WallType,
@@ -180,6 +374,9 @@ pub enum TileType {
         open: bool,
         facing: Direction,
         hidden: GateVisibility,
+        /// When the gate stops being hidden
+        #[serde(default)]
+        reveal: Reveal,
     },
     OneWay {
         inverted: bool,
@@ -188,17 +385,101 @@ pub enum TileType {
     Button {
         pressed: bool,
         inverted: bool,
-        target: ObjectCoordinate,
+        /// Levels with a single `target` are still accepted
+        #[serde(alias = "target", deserialize_with = "one_or_many")]
+        targets: Vec<ObjectCoordinate>,
+    },
+    /// Powered only while the player stands on it
+    PressurePlate {
+        inverted: bool,
+        #[serde(alias = "target", deserialize_with = "one_or_many")]
+        targets: Vec<ObjectCoordinate>,
+        #[serde(default)]
+        occupied: bool,
+    },
+    /// Powered for `duration` seconds after the player stepped on it
+    TimedButton {
+        duration: f32,
+        inverted: bool,
+        #[serde(alias = "target", deserialize_with = "one_or_many")]
+        targets: Vec<ObjectCoordinate>,
+        /// The seconds until the button is released
+        #[serde(default)]
+        remaining: f32,
     },
 }
 
+fn one_or_many<'de, D>(deserializer: D) -> Result<Vec<ObjectCoordinate>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(ObjectCoordinate),
+        Many(Vec<ObjectCoordinate>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(coord) => vec![coord],
+        OneOrMany::Many(coords) => coords,
+    })
+}
+
+// the seconds of timed buttons are never NaN, `LevelTemplate::validate` rejects them
+impl Eq for TileType {}
+
+impl Hash for TileType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            TileType::Wall { kind } => kind.hash(state),
+            TileType::Path | TileType::Ladder | TileType::Start => {}
+            TileType::Goal { active } => active.hash(state),
+            TileType::Gate {
+                open,
+                facing,
+                hidden,
+                reveal,
+            } => (open, facing, hidden, reveal).hash(state),
+            TileType::OneWay { inverted, facing } => (inverted, facing).hash(state),
+            TileType::Button {
+                pressed,
+                inverted,
+                targets,
+            } => (pressed, inverted, targets).hash(state),
+            TileType::PressurePlate {
+                inverted,
+                targets,
+                occupied,
+            } => (inverted, targets, occupied).hash(state),
+            TileType::TimedButton {
+                duration,
+                inverted,
+                targets,
+                remaining,
+            } => (duration.to_bits(), inverted, targets, remaining.to_bits()).hash(state),
+        }
+    }
+}
+
 impl TileType {
     pub fn apply_button(&mut self, active: bool) {
         match self {
             TileType::Goal {
                 active: active_goal,
             } => *active_goal = active,
-            TileType::Gate { open, .. } => *open = active,
+            TileType::Gate {
+                open,
+                hidden,
+                reveal,
+                ..
+            } => {
+                *open = active;
+                if active && *reveal == Reveal::WhenOpened {
+                    *hidden = GateVisibility::Visible;
+                }
+            }
             TileType::OneWay { inverted, .. } => *inverted = active,
             _ => error!(
                 "Tried to change the state of a single State Tile or Button Tile with a Button!"
@@ -206,34 +487,107 @@ impl TileType {
         }
     }
 
-    pub fn step_on(&mut self) -> Option<Box<dyn Fn(&mut GameState)>> {
+    /// Whether the tile can be controlled by a button or the wiring
+    pub fn is_switchable(&self) -> bool {
+        matches!(
+            self,
+            TileType::Goal { .. } | TileType::Gate { .. } | TileType::OneWay { .. }
+        )
+    }
+
+    /// Whether a hidden gate may look like the tile, i.e. it is neither a gate nor a button
+    pub fn can_be_mimicked(&self) -> bool {
+        !matches!(self, TileType::Gate { .. }) && self.power().is_none()
+    }
+
+    /// The signal a button sends to its targets and the wiring, `None` for other tiles
+    pub fn power(&self) -> Option<bool> {
+        match self {
+            TileType::Button {
+                pressed, inverted, ..
+            } => Some(*pressed ^ *inverted),
+            TileType::PressurePlate {
+                occupied, inverted, ..
+            } => Some(*occupied ^ *inverted),
+            TileType::TimedButton {
+                remaining,
+                inverted,
+                ..
+            } => Some((*remaining > 0.0) ^ *inverted),
+            _ => None,
+        }
+    }
+
+    /// The tiles switched by a button, `None` for other tiles
+    pub fn targets(&self) -> Option<&[ObjectCoordinate]> {
+        match self {
+            TileType::Button { targets, .. }
+            | TileType::PressurePlate { targets, .. }
+            | TileType::TimedButton { targets, .. } => Some(targets),
+            _ => None,
+        }
+    }
+
+    /// The event of the button at `coord` changing its power
+    fn toggled(&self, coord: ObjectCoordinate) -> Option<GameEvent> {
+        Some(GameEvent::ButtonToggled {
+            button: coord,
+            power: self.power()?,
+        })
+    }
+
+    /// Counts a timed button down by `dt` seconds, returns whether it was released
+    pub fn count_down(&mut self, dt: f32) -> bool {
+        match self {
+            TileType::TimedButton { remaining, .. } if *remaining > 0.0 => {
+                *remaining = (*remaining - dt).max(0.0);
+                *remaining == 0.0
+            }
+            _ => false,
+        }
+    }
+
+    /// The player leaves this tile at `coord`
+    pub fn step_off(&mut self, coord: ObjectCoordinate) -> Option<GameEvent> {
+        match self {
+            TileType::PressurePlate { occupied, .. } => {
+                trace!("Stepping off a Pressure Plate");
+                *occupied = false;
+                self.toggled(coord)
+            }
+            _ => None,
+        }
+    }
+
+    /// The player enters this tile at `coord`
+    pub fn step_on(&mut self, coord: ObjectCoordinate) -> Option<GameEvent> {
         match self {
             TileType::Goal { active: true } => {
                 trace!("Goal reached!");
-                Some(Box::new(|game| {
-                    if let GameState::GameState { level_template, .. } = game {
-                        *game = GameState::Won {
-                            level_template: level_template.clone(),
-                        }
-                    }
-                }))
+                Some(GameEvent::GoalReached { goal: coord })
             }
-            TileType::Button {
-                pressed,
-                inverted,
-                target,
-            } => {
+            TileType::Ladder => {
+                trace!("Stepping on a Ladder");
+                Some(GameEvent::LadderEntered { ladder: coord })
+            }
+            TileType::Button { pressed, .. } => {
                 trace!("Stepping on a Button");
                 *pressed = !*pressed;
-                let power = *pressed ^ *inverted;
-                let t = *target;
-                Some(Box::new(move |game: &mut GameState| {
-                    if let GameState::GameState { level_state, .. } = game {
-                        if let Some(tile) = level_state.tile_map.get_mut(&t) {
-                            tile.apply_button(power)
-                        }
-                    }
-                }))
+                self.toggled(coord)
+            }
+            TileType::PressurePlate { occupied, .. } => {
+                trace!("Stepping on a Pressure Plate");
+                *occupied = true;
+                self.toggled(coord)
+            }
+            TileType::TimedButton {
+                duration,
+                remaining,
+                ..
+            } => {
+                trace!("Stepping on a Timed Button");
+                *remaining = *duration;
+                self.toggled(coord)
             }
             _ => None,
         }
@@ -261,11 +615,35 @@ impl TileType {
         }
     }
 
-    //TODO directionality for OneWay
+    /// The direction a one-way can be passed in, flipped while it is inverted
+    fn passage(&self) -> Option<Direction> {
+        match self {
+            TileType::OneWay { inverted, facing } if *inverted => Some(facing.inverted()),
+            TileType::OneWay { facing, .. } => Some(*facing),
+            _ => None,
+        }
+    }
+
+    /// Whether the tile can be entered through its `from` side
+    pub fn can_enter(&self, from: Direction) -> bool {
+        !self.is_solid()
+            && self
+                .passage()
+                .is_none_or(|passage| from == passage.inverted())
+    }
+
+    /// Whether the tile can be left through its `towards` side
+    pub fn can_leave(&self, towards: Direction) -> bool {
+        self.passage().is_none_or(|passage| towards == passage)
+    }
+
+    /// Whether the tile can not be entered from any side, see [`TileType::can_enter`]
     pub fn is_solid(&self) -> bool {
         match self {
             TileType::Wall { .. } => true,
             TileType::Button { .. } => false,
+            TileType::PressurePlate { .. } => false,
+            TileType::TimedButton { .. } => false,
             TileType::Path => false,
             TileType::Start => false,
             TileType::Goal { .. } => false,
@@ -282,6 +660,12 @@ impl TileType {
             TileType::Ladder => TileTextureIndex::Ladder,
             TileType::Goal { active } => TileTextureIndex::Goal { active: *active },
             TileType::Button { pressed, .. } => TileTextureIndex::Button { pressed: *pressed },
+            TileType::PressurePlate { occupied, .. } => {
+                TileTextureIndex::PressurePlate { pressed: *occupied }
+            }
+            TileType::TimedButton { remaining, .. } => TileTextureIndex::TimedButton {
+                pressed: *remaining > 0.0,
+            },
             TileType::OneWay {
                 facing,
                 inverted: false,
@@ -297,6 +681,7 @@ impl TileType {
                 open,
                 facing,
                 hidden: GateVisibility::Visible,
+                ..
             }
             | TileType::Gate {
                 open: open @ true,
@@ -308,50 +693,39 @@ impl TileType {
             },
             TileType::Gate {
                 open: false,
-                facing: _,
                 hidden: GateVisibility::Hidden(mimic),
+                ..
             } => mimic.tile_texture_id(),
         }
     }
 }
 
-impl TileTextureIndex {
-    pub fn file_name(&self) -> String {
-        match self {
-            TileTextureIndex::Path => "path".to_string(),
-            TileTextureIndex::Start => "start".to_string(),
-            TileTextureIndex::Goal { active } => {
-                format!("goal{}", if !active { "_inactive" } else { "" })
-            }
-            // we should never need a texture for a hidden and closed gate because it is hidden
-            TileTextureIndex::Gate { open, facing } => format!(
-                "{}_gate_{}",
-                if *open { "open" } else { "closed" },
-                facing.file_modifier()
-            ),
-            TileTextureIndex::Ladder => "ladder".to_string(),
-            TileTextureIndex::OneWay { facing } => format!("one_way_{}", facing.file_modifier()),
-            TileTextureIndex::Wall { kind } => format!("wall_{}", kind.file_modifier()),
-            TileTextureIndex::Button { pressed } => {
-                format!("button{}", if *pressed { "_pressed" } else { "" })
-            }
-        }
-    }
-}
-
-impl ToString for TileTextureIndex {
-    fn to_string(&self) -> String {
-        self.file_name()
-    }
-}
-
-#[derive(Debug, Ord, PartialOrd, PartialEq, Eq, Clone, Copy, Deserialize, Serialize)]
+#[derive(Debug, Ord, PartialOrd, PartialEq, Eq, Hash, Clone, Copy, Deserialize, Serialize)]
 pub struct ObjectCoordinate {
     pub x: i64,
     pub y: i64,
+    /// The floor, levels without floors are on floor `0`
+    #[serde(default)]
+    pub z: i64,
 }
 
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+impl ObjectCoordinate {
+    pub fn neighbour(self, direction: Direction) -> Self {
+        let ObjectCoordinate { x, y, z } = self;
+        match direction {
+            Direction::NORTH => ObjectCoordinate { x, y: y - 1, z },
+            Direction::SOUTH => ObjectCoordinate { x, y: y + 1, z },
+            Direction::EAST => ObjectCoordinate { x: x + 1, y, z },
+            Direction::WEST => ObjectCoordinate { x: x - 1, y, z },
+            Direction::UP => ObjectCoordinate { x, y, z: z + 1 },
+            Direction::DOWN => ObjectCoordinate { x, y, z: z - 1 },
+        }
+    }
+}
+
+#[derive(
+    Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize, Bounded, Enumerable,
+)]
 pub struct Connections {
     pub up: bool,
     pub down: bool,
@@ -359,12 +733,22 @@ pub struct Connections {
     pub right: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GateVisibility {
     Visible,
+    /// Drawn like the tile while closed, see [`TileType::can_be_mimicked`]
     Hidden(Box<TileType>),
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Reveal {
+    /// Once the gate is opened
+    #[default]
+    WhenOpened,
+    /// Once the player stands next to the gate on the same floor
+    WhenAdjacent,
+}
+
 pub mod saving {
     use crate::game::LevelTemplate;
     use std::fmt::{Display, Formatter};
@@ -428,7 +812,72 @@ pub mod saving {
     }
 }
 
+pub mod validation {
+    use crate::game::ObjectCoordinate;
+    use std::fmt::{Display, Formatter};
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum ValidationError {
+        NoStart,
+        MultipleStarts(Vec<ObjectCoordinate>),
+        /// The player speed is not a positive number
+        InvalidSpeed,
+        /// A button targets a tile that can not be switched
+        InvalidTarget {
+            source: ObjectCoordinate,
+            target: ObjectCoordinate,
+        },
+        /// A wiring output targets a tile that can not be switched
+        InvalidOutput(ObjectCoordinate),
+        /// A hidden gate mimics a tile that can not be mimicked
+        InvalidMimic(ObjectCoordinate),
+        /// A timed button counts down a negative or non-finite number of seconds
+        InvalidDuration(ObjectCoordinate),
+        UnknownNode(String),
+        WiringCycle(String),
+    }
+
+    impl Display for ValidationError {
+        fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+            match self {
+                ValidationError::NoStart => f.write_str("the level has no start tile"),
+                ValidationError::MultipleStarts(starts) => {
+                    write!(f, "the level has multiple start tiles at {:?}", starts)
+                }
+                ValidationError::InvalidSpeed => {
+                    f.write_str("the player speed is not a positive number")
+                }
+                ValidationError::InvalidTarget { source, target } => write!(
+                    f,
+                    "the button at {:?} targets {:?}, which can not be switched",
+                    source, target
+                ),
+                ValidationError::InvalidOutput(target) => write!(
+                    f,
+                    "the wiring targets {:?}, which can not be switched",
+                    target
+                ),
+                ValidationError::InvalidMimic(gate) => {
+                    write!(f, "the hidden gate at {:?} mimics a gate or button", gate)
+                }
+                ValidationError::InvalidDuration(button) => write!(
+                    f,
+                    "the timed button at {:?} has a negative or non-finite duration",
+                    button
+                ),
+                ValidationError::UnknownNode(name) => {
+                    write!(f, "the wiring refers to the unknown node {:?}", name)
+                }
+                ValidationError::WiringCycle(name) => {
+                    write!(f, "the wiring node {:?} depends on itself", name)
+                }
+            }
+        }
+    }
+}
+
 pub mod loading {
+    use super::validation::ValidationError;
     use crate::game::LevelTemplate;
     use std::fs::File;
     use std::io::Read;
@@ -437,6 +886,13 @@ pub mod loading {
         IO(std::io::Error),
         Deserialize(ron::de::Error),
         Spanned(ron::error::SpannedError),
+        Invalid(ValidationError),
+    }
+
+    impl From<ValidationError> for LoadingError {
+        fn from(err: ValidationError) -> Self {
+            LoadingError::Invalid(err)
+        }
     }
 
     impl From<std::io::Error> for LoadingError {
@@ -457,6 +913,7 @@ pub mod loading {
         }
     }
 
+    /// The valid levels in the `levels` directory, ordered by their file names
     pub fn load_levels(asset_path: &std::path::Path) -> Result<Vec<LevelTemplate>, LoadingError> {
         log::info!("Loading Levels!");
         let path = asset_path.join("levels");
@@ -467,9 +924,10 @@ pub mod loading {
             std::fs::create_dir_all(&path)?;
         }
 
-        let dir = path.read_dir()?;
+        let mut entries: Vec<_> = path.read_dir()?.flatten().collect();
+        entries.sort_by_key(|entry| entry.file_name());
 
-        for entry in dir.flatten() {
+        for entry in entries {
             if let Ok(f_type) = entry.file_type() {
                 if f_type.is_file() {
                     if let Ok(level) = load_level(entry.path().as_path()) {
@@ -482,7 +940,7 @@ pub mod loading {
         Ok(levels)
     }
 
-    fn load_level(path: &std::path::Path) -> Result<LevelTemplate, LoadingError> {
+    pub fn load_level(path: &std::path::Path) -> Result<LevelTemplate, LoadingError> {
         log::info!("Loading level at '{}'!", path.display());
         let mut content = vec![];
 
@@ -493,6 +951,7 @@ pub mod loading {
         let mut des = ron::de::Deserializer::from_bytes(content.as_slice())?;
 
         let level = LevelTemplate::deserialize(&mut des)?;
+        level.validate()?;
         Ok(level)
     }
 }
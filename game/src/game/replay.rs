@@ -0,0 +1,479 @@
+//! Recording of the inputs of a play session and playing them back.
+//!
+//! Every tick is `TICK` long, so feeding the same inputs to the same level gives the same result.
+//! The inputs are only recorded when they change, together with the tick they change before.
+
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use super::level::loading::LoadingError;
+use super::level::saving::SavingError;
+use super::validation::ValidationError;
+use super::wiring::{Node, Signal};
+use super::{
+    Action, Direction, EastWestAxis, GameEvent, GameState, GateVisibility, LevelState,
+    LevelTemplate, MovementMode, NorthSouthAxis, ObjectCoordinate, Orientation, Reveal, TileType,
+    WallType, TICK,
+};
+
+/// The file extension of replays
+pub const REPLAY_EXTENSION: &str = "replay.ron";
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub level_name: String,
+    /// See [`level_hash`]
+    pub level_hash: u64,
+    /// The number of ticks played
+    pub ticks: u64,
+    pub inputs: Vec<Recorded>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Recorded {
+    /// The number of ticks played before the input
+    pub tick: u64,
+    pub input: Input,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Input {
+    /// The actions held down from now on
+    Held(Vec<Action>),
+    Undo,
+    Redo,
+    Hint,
+}
+
+/// A hash of what is played in `level`, stable across builds so that replays can be checked in.
+///
+/// The level is walked field by field instead of hashing its serialization,
+/// so that changes to the file format keep the hash of unchanged levels.
+pub fn level_hash(level: &LevelTemplate) -> u64 {
+    let mut hasher = Fnv::default();
+    hasher.str(&level.name);
+    hasher.u64(level.player_speed.to_bits().into());
+    hasher.str(match level.movement {
+        MovementMode::Continuous => "Continuous",
+        MovementMode::Grid => "Grid",
+    });
+    hasher.direction(level.spawn_facing);
+    let LevelState { tile_map, wiring } = &level.init_state;
+    hasher.len(tile_map.len());
+    for (coord, tile) in tile_map {
+        hasher.coord(*coord);
+        hasher.tile(tile);
+    }
+    hasher.len(wiring.nodes.len());
+    for (name, node) in &wiring.nodes {
+        hasher.str(name);
+        hasher.node(node);
+    }
+    hasher.len(wiring.outputs.len());
+    for output in &wiring.outputs {
+        hasher.signal(&output.signal);
+        hasher.coord(output.target);
+    }
+    hasher.0
+}
+
+/// FNV-1a, the hashers of std may change between releases
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv {
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn len(&mut self, len: usize) {
+        self.u64(len as u64);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.bytes(&[u8::from(value)]);
+    }
+
+    fn str(&mut self, value: &str) {
+        self.len(value.len());
+        self.bytes(value.as_bytes());
+    }
+
+    fn direction(&mut self, direction: Direction) {
+        self.str(match direction {
+            Direction::UP => "UP",
+            Direction::DOWN => "DOWN",
+            Direction::NORTH => "NORTH",
+            Direction::EAST => "EAST",
+            Direction::SOUTH => "SOUTH",
+            Direction::WEST => "WEST",
+        });
+    }
+
+    fn axes(&mut self, north_south: NorthSouthAxis, east_west: EastWestAxis) {
+        self.str(match north_south {
+            NorthSouthAxis::North => "North",
+            NorthSouthAxis::South => "South",
+        });
+        self.str(match east_west {
+            EastWestAxis::East => "East",
+            EastWestAxis::West => "West",
+        });
+    }
+
+    fn wall(&mut self, kind: WallType) {
+        match kind {
+            WallType::Single { facing } => {
+                self.str("Single");
+                self.direction(facing);
+            }
+            WallType::Double { orientation } => {
+                self.str("Double");
+                self.str(match orientation {
+                    Orientation::Horizontal => "Horizontal",
+                    Orientation::Vertical => "Vertical",
+                });
+            }
+            WallType::Corner {
+                north_south_facing,
+                east_west_facing,
+            } => {
+                self.str("Corner");
+                self.axes(north_south_facing, east_west_facing);
+            }
+            WallType::InnerCorner {
+                north_south_facing,
+                east_west_facing,
+            } => {
+                self.str("InnerCorner");
+                self.axes(north_south_facing, east_west_facing);
+            }
+            WallType::End { facing } => {
+                self.str("End");
+                self.direction(facing);
+            }
+            WallType::Lone => self.str("Lone"),
+            WallType::Center => self.str("Center"),
+        }
+    }
+
+    fn coord(&mut self, ObjectCoordinate { x, y, z }: ObjectCoordinate) {
+        for value in [x, y, z] {
+            self.u64(value as u64);
+        }
+    }
+
+    fn coords(&mut self, coords: &[ObjectCoordinate]) {
+        self.len(coords.len());
+        for coord in coords {
+            self.coord(*coord);
+        }
+    }
+
+    fn tile(&mut self, tile: &TileType) {
+        match tile {
+            TileType::Wall { kind } => {
+                self.str("Wall");
+                self.wall(*kind);
+            }
+            TileType::Path => self.str("Path"),
+            TileType::Ladder => self.str("Ladder"),
+            TileType::Start => self.str("Start"),
+            TileType::Goal { active } => {
+                self.str("Goal");
+                self.bool(*active);
+            }
+            TileType::Gate {
+                open,
+                facing,
+                hidden,
+                reveal,
+            } => {
+                self.str("Gate");
+                self.bool(*open);
+                self.direction(*facing);
+                match hidden {
+                    GateVisibility::Visible => self.str("Visible"),
+                    GateVisibility::Hidden(mimic) => {
+                        self.str("Hidden");
+                        self.tile(mimic);
+                    }
+                }
+                self.str(match reveal {
+                    Reveal::WhenOpened => "WhenOpened",
+                    Reveal::WhenAdjacent => "WhenAdjacent",
+                });
+            }
+            TileType::OneWay { inverted, facing } => {
+                self.str("OneWay");
+                self.bool(*inverted);
+                self.direction(*facing);
+            }
+            TileType::Button {
+                pressed,
+                inverted,
+                targets,
+            } => {
+                self.str("Button");
+                self.bool(*pressed);
+                self.bool(*inverted);
+                self.coords(targets);
+            }
+            TileType::PressurePlate {
+                inverted,
+                targets,
+                occupied,
+            } => {
+                self.str("PressurePlate");
+                self.bool(*inverted);
+                self.coords(targets);
+                self.bool(*occupied);
+            }
+            TileType::TimedButton {
+                duration,
+                inverted,
+                targets,
+                remaining,
+            } => {
+                self.str("TimedButton");
+                self.u64(duration.to_bits().into());
+                self.bool(*inverted);
+                self.coords(targets);
+                self.u64(remaining.to_bits().into());
+            }
+        }
+    }
+
+    fn signal(&mut self, signal: &Signal) {
+        match signal {
+            Signal::Tile(coord) => {
+                self.str("Tile");
+                self.coord(*coord);
+            }
+            Signal::Node(name) => {
+                self.str("Node");
+                self.str(name);
+            }
+        }
+    }
+
+    fn signals(&mut self, signals: &[Signal]) {
+        self.len(signals.len());
+        for signal in signals {
+            self.signal(signal);
+        }
+    }
+
+    fn node(&mut self, node: &Node) {
+        match node {
+            Node::And(signals) => {
+                self.str("And");
+                self.signals(signals);
+            }
+            Node::Or(signals) => {
+                self.str("Or");
+                self.signals(signals);
+            }
+            Node::Xor(signals) => {
+                self.str("Xor");
+                self.signals(signals);
+            }
+            Node::Not(signal) => {
+                self.str("Not");
+                self.signal(signal);
+            }
+            Node::Latch {
+                set,
+                reset,
+                latched,
+            } => {
+                self.str("Latch");
+                self.signal(set);
+                self.signal(reset);
+                self.bool(*latched);
+            }
+        }
+    }
+}
+
+impl Replay {
+    pub fn new(level: &LevelTemplate) -> Self {
+        Replay {
+            level_name: level.name.clone(),
+            level_hash: level_hash(level),
+            ticks: 0,
+            inputs: vec![],
+        }
+    }
+
+    fn held(&self) -> &[Action] {
+        self.inputs
+            .iter()
+            .rev()
+            .find_map(|recorded| match &recorded.input {
+                Input::Held(actions) => Some(actions.as_slice()),
+                _ => None,
+            })
+            .unwrap_or(&[])
+    }
+
+    /// Records a tick with the `held` actions
+    pub(super) fn record_tick(&mut self, held: &[Action]) {
+        if self.held() != held {
+            self.record(Input::Held(held.to_vec()));
+        }
+        self.ticks += 1;
+    }
+
+    pub(super) fn record(&mut self, input: Input) {
+        self.inputs.push(Recorded {
+            tick: self.ticks,
+            input,
+        });
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SavingError> {
+        let out = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        info!("Writing replay of {} to {:?}.", self.level_name, path);
+        File::create(path)?.write_all(out.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Replay, LoadingError> {
+        let mut content = vec![];
+        File::open(path)?.read_to_end(&mut content)?;
+        Ok(ron::de::from_bytes(&content)?)
+    }
+
+    /// Plays the whole replay on `level`
+    pub fn play(&self, level: Rc<LevelTemplate>) -> Result<GameState, ReplayError> {
+        let (mut replayer, mut state) = Replayer::start(self.clone(), level)?;
+        while replayer.tick(&mut state).is_some() {}
+        Ok(state)
+    }
+}
+
+/// The replays in the `replays` directory, ordered by their file names
+pub fn load_replays(asset_path: &Path) -> Vec<Replay> {
+    let Ok(dir) = asset_path.join("replays").read_dir() else {
+        return vec![];
+    };
+    let mut paths: Vec<_> = dir
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(REPLAY_EXTENSION))
+        })
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| Replay::load(path).ok())
+        .collect()
+}
+
+/// Feeds the inputs of a replay to a game one tick at a time
+#[derive(Clone, Debug)]
+pub struct Replayer {
+    replay: Replay,
+    /// The index of the next input
+    next: usize,
+    tick: u64,
+    held: Vec<Action>,
+}
+
+impl Replayer {
+    /// Starts `level` to play `replay` on it
+    pub fn start(
+        replay: Replay,
+        level: Rc<LevelTemplate>,
+    ) -> Result<(Replayer, GameState), ReplayError> {
+        let actual = level_hash(&level);
+        if replay.level_hash != actual {
+            return Err(ReplayError::LevelMismatch {
+                expected: replay.level_hash,
+                actual,
+            });
+        }
+        let state = GameState::new(level).map_err(ReplayError::Invalid)?;
+        let replayer = Replayer {
+            replay,
+            next: 0,
+            tick: 0,
+            held: vec![],
+        };
+        Ok((replayer, state))
+    }
+
+    /// Plays the next tick of the replay, `None` once all ticks have been played
+    pub fn tick(&mut self, state: &mut GameState) -> Option<Vec<GameEvent>> {
+        if self.tick >= self.replay.ticks {
+            return None;
+        }
+        while let Some(recorded) = self
+            .replay
+            .inputs
+            .get(self.next)
+            .filter(|recorded| recorded.tick <= self.tick)
+        {
+            match &recorded.input {
+                Input::Held(actions) => self.held.clone_from(actions),
+                Input::Undo => {
+                    state.undo();
+                }
+                Input::Redo => {
+                    state.redo();
+                }
+                Input::Hint => state.count_hint(),
+            }
+            self.next += 1;
+        }
+        self.tick += 1;
+        Some(state.tick(TICK, &self.held))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The replay was recorded on a different version of the level
+    LevelMismatch {
+        expected: u64,
+        actual: u64,
+    },
+    Invalid(ValidationError),
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ReplayError::LevelMismatch { expected, actual } => write!(
+                f,
+                "the replay was recorded on level {:x} but the level is {:x}",
+                expected, actual
+            ),
+            ReplayError::Invalid(err) => Display::fmt(err, f),
+        }
+    }
+}
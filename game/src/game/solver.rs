@@ -0,0 +1,141 @@
+//! Breadth first search for the shortest way through a level.
+//!
+//! The player is moved a whole tile at a time like in [`MovementMode::Grid`](super::MovementMode),
+//! so a state of the search is the tile of the player together with the state of every tile.
+//! Each step takes one tile divided by the player speed, which is the time timed buttons count down.
+
+use std::collections::{HashMap, VecDeque};
+
+use super::replay::Replay;
+use super::{Direction, GameState, History, LevelState, ObjectCoordinate};
+
+/// The directions the player can move in, floors are changed by ladders
+const DIRECTIONS: [Direction; 4] = [
+    Direction::NORTH,
+    Direction::EAST,
+    Direction::SOUTH,
+    Direction::WEST,
+];
+
+/// The most states searched before giving up
+pub const MAX_STATES: usize = 100_000;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Solution {
+    /// The shortest sequence of steps that wins the level
+    Solved(Vec<Direction>),
+    /// The level can not be won from the state
+    Unsolvable,
+    /// More than [`MAX_STATES`] states would have to be searched
+    GaveUp,
+}
+
+/// Identifies a state of the search
+type Key = (ObjectCoordinate, LevelState);
+
+fn key(state: &GameState) -> Option<Key> {
+    match state {
+        GameState::GameState {
+            old_position,
+            level_state,
+            ..
+        } => Some((*old_position, level_state.clone())),
+        GameState::Won { .. } => None,
+    }
+}
+
+/// A copy of `state` without the records of how it was reached, so that the states of the
+/// search are cheap to clone
+fn root(state: &GameState) -> GameState {
+    let mut root = state.clone();
+    if let GameState::GameState {
+        history,
+        events,
+        replay,
+        ..
+    } = &mut root
+    {
+        *history = History::default();
+        events.clear();
+        *replay = Replay::default();
+    }
+    root
+}
+
+/// Searches the shortest way to win the level from `state`
+pub fn solve(state: &GameState) -> Solution {
+    let Some(start) = key(state) else {
+        return Solution::Solved(vec![]);
+    };
+    // the state each state was reached from and the step taken, by the index of the state
+    let mut steps: Vec<Option<(usize, Direction)>> = vec![None];
+    let mut visited: HashMap<Key, usize> = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([(0, root(state))]);
+
+    while let Some((current, state)) = queue.pop_front() {
+        for direction in DIRECTIONS {
+            let mut next = state.clone();
+            if !next.step(direction) {
+                continue;
+            }
+            let Some(next_key) = key(&next) else {
+                let mut path = vec![direction];
+                let mut at = current;
+                while let Some((previous, direction)) = steps[at] {
+                    path.push(direction);
+                    at = previous;
+                }
+                path.reverse();
+                return Solution::Solved(path);
+            };
+            if visited.contains_key(&next_key) {
+                continue;
+            }
+            if visited.len() >= MAX_STATES {
+                return Solution::GaveUp;
+            }
+            visited.insert(next_key, steps.len());
+            queue.push_back((steps.len(), next));
+            steps.push(Some((current, direction)));
+        }
+    }
+    Solution::Unsolvable
+}
+
+impl GameState {
+    /// Moves the player onto the neighbouring tile in `direction` at once, including everything
+    /// the move causes, returns whether the player could move
+    pub(super) fn step(&mut self, direction: Direction) -> bool {
+        let GameState::GameState {
+            position,
+            old_position,
+            facing,
+            tween,
+            level_template,
+            level_state,
+            ..
+        } = self
+        else {
+            return false;
+        };
+        if !level_state.can_move(old_position, direction) {
+            return false;
+        }
+        let to = old_position.neighbour(direction);
+        let duration = 1.0 / level_template.player_speed;
+        *facing = direction;
+        *tween = None;
+        *position = to.into();
+        self.enter_tile(to);
+        if let GameState::GameState {
+            level_state,
+            events,
+            ..
+        } = self
+        {
+            events.extend(level_state.update_timers(duration));
+        }
+        self.process_events_and_wiring();
+        true
+    }
+}
@@ -9,15 +9,16 @@ pub fn test_level() -> LevelTemplate {
     use WallType::*;
 
     let mut tile_map = BTreeMap::new();
-    tile_map.insert(ObjectCoordinate { x: 0, y: 0 }, TileType::Start);
-    tile_map.insert(ObjectCoordinate { x: 0, y: 1 }, TileType::Path);
-    tile_map.insert(ObjectCoordinate { x: 1, y: 1 }, TileType::Path);
-    tile_map.insert(ObjectCoordinate { x: 2, y: 1 }, TileType::Path);
-    tile_map.insert(ObjectCoordinate { x: 2, y: 2 }, TileType::Path);
-    tile_map.insert(ObjectCoordinate { x: 2, y: 3 }, TileType::Path);
-    tile_map.insert(ObjectCoordinate { x: 1, y: 3 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 0, y: 0, z: 0 }, TileType::Start);
+    tile_map.insert(ObjectCoordinate { x: -1, y: 0, z: 0 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 0, y: 1, z: 0 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 1, y: 1, z: 0 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 2, y: 1, z: 0 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 2, y: 2, z: 0 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 2, y: 3, z: 0 }, TileType::Path);
+    tile_map.insert(ObjectCoordinate { x: 1, y: 3, z: 0 }, TileType::Path);
     tile_map.insert(
-        ObjectCoordinate { x: 0, y: 2 },
+        ObjectCoordinate { x: 0, y: 2, z: 0 },
         TileType::Wall {
             kind: Corner {
                 north_south_facing: North,
@@ -26,7 +27,7 @@ pub fn test_level() -> LevelTemplate {
         },
     );
     tile_map.insert(
-        ObjectCoordinate { x: 1, y: 2 },
+        ObjectCoordinate { x: 1, y: 2, z: 0 },
         TileType::Wall {
             kind: Double {
                 orientation: Horizontal,
@@ -34,7 +35,7 @@ pub fn test_level() -> LevelTemplate {
         },
     );
     tile_map.insert(
-        ObjectCoordinate { x: -1, y: 2 },
+        ObjectCoordinate { x: -1, y: 2, z: 0 },
         TileType::Wall {
             kind: Double {
                 orientation: Horizontal,
@@ -42,19 +43,25 @@ pub fn test_level() -> LevelTemplate {
         },
     );
     tile_map.insert(
-        ObjectCoordinate { x: 0, y: 3 },
+        ObjectCoordinate { x: 0, y: 3, z: 0 },
         TileType::Goal { active: false },
     );
     tile_map.insert(
-        ObjectCoordinate { x: -1, y: -1 },
+        ObjectCoordinate { x: -1, y: -1, z: 0 },
         TileType::Button {
             pressed: false,
             inverted: false,
-            target: ObjectCoordinate { x: 0, y: 3 },
+            targets: vec![ObjectCoordinate { x: 0, y: 3, z: 0 }],
         },
     );
     LevelTemplate {
         name: String::from("Test"),
-        init_state: LevelState { tile_map },
+        init_state: LevelState {
+            tile_map,
+            wiring: Default::default(),
+        },
+        player_speed: DEFAULT_PLAYER_SPEED,
+        movement: MovementMode::Continuous,
+        spawn_facing: Direction::SOUTH,
     }
 }
@@ -0,0 +1,222 @@
+//! Logic between the inputs of a level, i.e. buttons, and the tiles they switch.
+//!
+//! The wiring is evaluated every tick and each output that would switch its target queues a
+//! [`GameEvent::TargetPowered`] like a button would, so an output overrides any button that targets
+//! the same tile directly.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use log::error;
+use serde::{Deserialize, Serialize};
+
+use super::validation::ValidationError;
+use super::{GameEvent, LevelState, ObjectCoordinate, TileType};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Wiring {
+    #[serde(default)]
+    pub nodes: BTreeMap<String, Node>,
+    #[serde(default)]
+    pub outputs: Vec<Output>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Signal {
+    /// The power of the tile at the coordinate, see [`TileType::power`]
+    Tile(ObjectCoordinate),
+    /// The value of the node with the name
+    Node(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Node {
+    And(Vec<Signal>),
+    Or(Vec<Signal>),
+    /// Whether an odd number of the signals is on
+    Xor(Vec<Signal>),
+    Not(Signal),
+    /// Turns on with `set`, off with `reset` and otherwise keeps its value,
+    /// `set` wins if both are on
+    Latch {
+        set: Signal,
+        reset: Signal,
+        #[serde(default)]
+        latched: bool,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Output {
+    pub signal: Signal,
+    pub target: ObjectCoordinate,
+}
+
+struct Evaluation<'a> {
+    tile_map: &'a BTreeMap<ObjectCoordinate, TileType>,
+    nodes: &'a BTreeMap<String, Node>,
+    values: BTreeMap<&'a str, bool>,
+    visiting: BTreeSet<&'a str>,
+}
+
+impl<'a> Evaluation<'a> {
+    fn signal(&mut self, signal: &'a Signal) -> bool {
+        match signal {
+            Signal::Tile(coord) => self
+                .tile_map
+                .get(coord)
+                .and_then(TileType::power)
+                .unwrap_or(false),
+            Signal::Node(name) => self.node(name),
+        }
+    }
+
+    fn all(&mut self, signals: &'a [Signal]) -> Vec<bool> {
+        signals.iter().map(|signal| self.signal(signal)).collect()
+    }
+
+    fn node(&mut self, name: &'a str) -> bool {
+        if let Some(value) = self.values.get(name) {
+            return *value;
+        }
+        let Some(node) = self.nodes.get(name) else {
+            error!("Wiring refers to the unknown node {:?}!", name);
+            return false;
+        };
+        if !self.visiting.insert(name) {
+            error!("Wiring node {:?} depends on itself!", name);
+            return false;
+        }
+        let value = match node {
+            Node::And(signals) => self.all(signals).into_iter().all(|value| value),
+            Node::Or(signals) => self.all(signals).into_iter().any(|value| value),
+            Node::Xor(signals) => self
+                .all(signals)
+                .into_iter()
+                .fold(false, |acc, value| acc ^ value),
+            Node::Not(signal) => !self.signal(signal),
+            Node::Latch {
+                set,
+                reset,
+                latched,
+            } => {
+                let set = self.signal(set);
+                let reset = self.signal(reset);
+                set || (*latched && !reset)
+            }
+        };
+        self.visiting.remove(name);
+        self.values.insert(name, value);
+        value
+    }
+}
+
+impl LevelState {
+    /// Evaluates every node of the wiring, returns the events of the outputs that switch their targets
+    pub fn update_wiring(&mut self) -> Vec<GameEvent> {
+        let mut evaluation = Evaluation {
+            tile_map: &self.tile_map,
+            nodes: &self.wiring.nodes,
+            values: BTreeMap::new(),
+            visiting: BTreeSet::new(),
+        };
+        for name in self.wiring.nodes.keys() {
+            evaluation.node(name);
+        }
+        let outputs: Vec<_> = self
+            .wiring
+            .outputs
+            .iter()
+            .map(|output| (output.target, evaluation.signal(&output.signal)))
+            .collect();
+        let values: BTreeMap<String, bool> = evaluation
+            .values
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+
+        for (name, node) in &mut self.wiring.nodes {
+            if let Node::Latch { latched, .. } = node {
+                *latched = values[name];
+            }
+        }
+        outputs
+            .into_iter()
+            .filter(|&(target, power)| {
+                self.tile_map.get(&target).is_some_and(|tile| {
+                    let mut switched = tile.clone();
+                    switched.apply_button(power);
+                    switched != *tile
+                })
+            })
+            .map(|(target, power)| GameEvent::TargetPowered { target, power })
+            .collect()
+    }
+
+    /// Checks that buttons and outputs target switchable tiles
+    /// and that the nodes exist and do not depend on themselves
+    pub fn validate_wiring(&self) -> Result<(), ValidationError> {
+        let switchable = |target: &ObjectCoordinate| {
+            self.tile_map
+                .get(target)
+                .is_some_and(TileType::is_switchable)
+        };
+        for (coord, tile) in &self.tile_map {
+            if let Some(targets) = tile.targets() {
+                if let Some(target) = targets.iter().find(|target| !switchable(target)) {
+                    return Err(ValidationError::InvalidTarget {
+                        source: *coord,
+                        target: *target,
+                    });
+                }
+            }
+        }
+        for output in &self.wiring.outputs {
+            if !switchable(&output.target) {
+                return Err(ValidationError::InvalidOutput(output.target));
+            }
+            self.validate_signal(&output.signal, &mut vec![])?;
+        }
+        for name in self.wiring.nodes.keys() {
+            self.validate_node(name, &mut vec![])?;
+        }
+        Ok(())
+    }
+
+    fn validate_signal<'a>(
+        &'a self,
+        signal: &'a Signal,
+        path: &mut Vec<&'a str>,
+    ) -> Result<(), ValidationError> {
+        match signal {
+            Signal::Tile(_) => Ok(()),
+            Signal::Node(name) => self.validate_node(name, path),
+        }
+    }
+
+    /// `path` are the nodes depending on the node `name`
+    fn validate_node<'a>(
+        &'a self,
+        name: &'a str,
+        path: &mut Vec<&'a str>,
+    ) -> Result<(), ValidationError> {
+        if path.contains(&name) {
+            return Err(ValidationError::WiringCycle(name.to_string()));
+        }
+        let node = self
+            .wiring
+            .nodes
+            .get(name)
+            .ok_or_else(|| ValidationError::UnknownNode(name.to_string()))?;
+        path.push(name);
+        let inputs: Vec<&Signal> = match node {
+            Node::And(signals) | Node::Or(signals) | Node::Xor(signals) => signals.iter().collect(),
+            Node::Not(signal) => vec![signal],
+            Node::Latch { set, reset, .. } => vec![set, reset],
+        };
+        for signal in inputs {
+            self.validate_signal(signal, path)?;
+        }
+        path.pop();
+        Ok(())
+    }
+}
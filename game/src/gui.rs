@@ -1,8 +1,10 @@
 use crate::{
-    app::{Action, UpdateAction},
+    app::UpdateAction,
     game::{
         color::{IN_GAME_BACKGROUND, MENU_BACKGROUND},
-        GameState, LevelTemplate, TileTextureIndex,
+        replay::{self, Replay, Replayer, REPLAY_EXTENSION},
+        Action, EventLog, GameEvent, GameState, Hint, HintSearch, LevelTemplate, Subscriber,
+        TileTextureIndex, TICK,
     },
     gui::MenuState::InGame,
 };
@@ -17,15 +19,73 @@ use std::fmt::Debug;
 use std::rc::Rc;
 
 use learning_conrod_core::{get_asset_path, gui::TextureMap};
+use log::{debug, error};
 
 #[derive(Debug)]
 pub enum MenuState {
-    InGame { state: GameState, paused: bool },
+    InGame {
+        state: Box<GameState>,
+        paused: bool,
+        /// The time in seconds not yet simulated by a tick
+        lag: f32,
+        /// The levels in the order they are played in
+        levels: LevelSelectState,
+        recent_events: EventLog,
+        /// Feeds the inputs while watching a replay
+        replayer: Option<Replayer>,
+        /// The hint being searched
+        hint_search: Option<HintSearch>,
+    },
     LevelSelect(LevelSelectState),
 }
 
-#[derive(Debug)]
-pub struct LevelSelectState(Vec<Rc<LevelTemplate>>);
+#[derive(Clone, Debug)]
+pub struct LevelSelectState {
+    levels: Rc<[Rc<LevelTemplate>]>,
+    replays: Rc<[Replay]>,
+}
+
+impl LevelSelectState {
+    /// The level played after `level`
+    fn next(&self, level: &Rc<LevelTemplate>) -> Option<Rc<LevelTemplate>> {
+        let index = self
+            .levels
+            .iter()
+            .position(|other| Rc::ptr_eq(other, level))?;
+        self.levels.get(index + 1).cloned()
+    }
+
+    /// The level `replay` was recorded on
+    fn level_of(&self, replay: &Replay) -> Option<Rc<LevelTemplate>> {
+        self.levels
+            .iter()
+            .find(|level| replay::level_hash(level) == replay.level_hash)
+            .cloned()
+    }
+}
+
+/// Writes `replay` to the `replays` directory, named after its level and the current time
+fn save_replay(replay: &Replay) {
+    let seconds = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    let path = get_asset_path().join("replays").join(format!(
+        "{}-{}.{}",
+        replay.level_name, seconds, REPLAY_EXTENSION
+    ));
+    if let Err(err) = replay.save(&path) {
+        error!("Failed to save the replay: {}", err);
+    }
+}
+
+enum WonChoice {
+    Retry,
+    Exit,
+    Next,
+}
+
+/// The most time in seconds simulated in a single update
+const MAX_LAG: f32 = 0.25;
 
 pub trait Menu: Debug {
     fn handle_esc(&mut self, window: WindowId) -> UpdateAction;
@@ -38,8 +98,56 @@ impl MenuState {
             .into_iter()
             .map(Rc::new)
             .collect();
+        let replays = replay::load_replays(get_asset_path().as_path()).into();
 
-        MenuState::LevelSelect(LevelSelectState(levels))
+        MenuState::LevelSelect(LevelSelectState { levels, replays })
+    }
+
+    /// Plays `state`, `levels` are used to find the next level
+    fn play(state: GameState, levels: LevelSelectState) -> Self {
+        MenuState::InGame {
+            state: Box::new(state),
+            paused: false,
+            lag: 0.0,
+            levels,
+            recent_events: EventLog::default(),
+            replayer: None,
+            hint_search: None,
+        }
+    }
+
+    /// Watches `replay` or stays in the level selection if its level is not found
+    fn watch(replay: Replay, levels: LevelSelectState) -> Self {
+        let Some(level) = levels.level_of(&replay) else {
+            error!("The level of the replay was not found");
+            return MenuState::LevelSelect(levels);
+        };
+        match Replayer::start(replay, level) {
+            Ok((replayer, state)) => MenuState::InGame {
+                state: Box::new(state),
+                paused: false,
+                lag: 0.0,
+                levels,
+                recent_events: EventLog::default(),
+                replayer: Some(replayer),
+                hint_search: None,
+            },
+            Err(err) => {
+                error!("{}", err);
+                MenuState::LevelSelect(levels)
+            }
+        }
+    }
+
+    /// Starts `level` or stays in the level selection if it is invalid
+    fn start_level(level: Rc<LevelTemplate>, levels: LevelSelectState) -> Self {
+        match GameState::new(level) {
+            Ok(state) => Self::play(state, levels),
+            Err(err) => {
+                error!("{}", err);
+                MenuState::LevelSelect(levels)
+            }
+        }
     }
 
     fn handle_esc(&mut self, _window: WindowId) -> UpdateAction {
@@ -47,6 +155,11 @@ impl MenuState {
             MenuState::InGame {
                 state: _state,
                 paused: true,
+                lag: _,
+                levels: _,
+                recent_events: _,
+                replayer: _,
+                hint_search: _,
             } => *self = Self::open_level_selection(),
             MenuState::LevelSelect(_) => {
                 return UpdateAction::Close;
@@ -54,6 +167,11 @@ impl MenuState {
             InGame {
                 state: _state,
                 paused: paused @ false,
+                lag: _,
+                levels: _,
+                recent_events: _,
+                replayer: _,
+                hint_search: _,
             } => *paused = true,
         }
 
@@ -73,6 +191,11 @@ impl MenuState {
             MenuState::InGame {
                 state: game_state,
                 paused: _,
+                lag: _,
+                levels: _,
+                recent_events: _,
+                replayer: _,
+                hint_search: _,
             } => {
                 let draw = app.draw();
                 draw.background().color(IN_GAME_BACKGROUND);
@@ -104,8 +227,13 @@ impl MenuState {
 
         match self {
             MenuState::InGame {
-                state: _,
+                state,
                 paused: paused @ true,
+                lag: _,
+                levels: _,
+                recent_events: _,
+                replayer: _,
+                hint_search: _,
             } => {
                 let back = egui::Window::new("Pause Menu")
                     .show(ctx, |ui| {
@@ -114,6 +242,9 @@ impl MenuState {
                             *paused = false;
                             false
                         } else {
+                            if ui.button("Save Replay").clicked() {
+                                save_replay(state.replay());
+                            }
                             ui.button("Exit Level").clicked()
                         }
                     })
@@ -130,47 +261,112 @@ impl MenuState {
                         ui.with_layout(egui::Layout::top_down(egui::Align::Center), |ui| {
                             ui.label("Level Selection");
 
-                            ui.group(|ui| {
-                                for level in level_list.0.iter() {
-                                    if ui.button(&level.name).clicked() {
-                                        return Some(level.clone());
+                            let level = ui
+                                .group(|ui| {
+                                    for level in level_list.levels.iter() {
+                                        if ui.button(&level.name).clicked() {
+                                            return Some(level.clone());
+                                        }
                                     }
-                                }
-                                None
-                            })
-                            .inner
+                                    None
+                                })
+                                .inner;
+
+                            ui.label("Replays");
+
+                            let replay = ui
+                                .group(|ui| {
+                                    for replay in level_list.replays.iter() {
+                                        let name = format!(
+                                            "{} ({} ticks)",
+                                            replay.level_name, replay.ticks
+                                        );
+                                        if ui.button(name).clicked() {
+                                            return Some(replay.clone());
+                                        }
+                                    }
+                                    None
+                                })
+                                .inner;
+                            (level, replay)
                         })
                     })
                     .inner;
 
-                if let Some(level) = result.inner {
-                    *self = MenuState::InGame {
-                        state: GameState::new(level),
-                        paused: false,
-                    }
+                match result.inner {
+                    (Some(level), _) => *self = Self::start_level(level, level_list.clone()),
+                    (None, Some(replay)) => *self = Self::watch(replay, level_list.clone()),
+                    (None, None) => {}
                 }
                 UpdateAction::Nothing
             }
             MenuState::InGame {
                 state,
                 paused: false,
+                lag,
+                levels,
+                recent_events,
+                replayer,
+                hint_search,
             } => {
-                match state {
-                    GameState::Won { .. } => {
-                        egui::Window::new("Won").show(ctx, |ui| {
-                            ui.label("Congratulations!");
-                            if ui.button("Retry Level").clicked() {
-                                // TODO
-                            }
-                            if ui.button("Exit Level").clicked() {
-                                // TODO
+                match state.as_mut() {
+                    GameState::Won {
+                        level_template,
+                        stats,
+                        replay,
+                    } => {
+                        let next = levels.next(level_template);
+                        let choice = egui::Window::new("Won")
+                            .show(ctx, |ui| {
+                                ui.label("Congratulations!");
+                                ui.label(format!("Time: {:.1}s", stats.time));
+                                ui.label(format!("Moves: {}", stats.moves));
+                                ui.label(format!("Hints: {}", stats.hints));
+                                if ui.button("Save Replay").clicked() {
+                                    save_replay(replay);
+                                }
+                                if ui.button("Retry Level").clicked() {
+                                    Some(WonChoice::Retry)
+                                } else if ui.button("Exit Level").clicked() {
+                                    Some(WonChoice::Exit)
+                                } else if ui
+                                    .add_enabled(next.is_some(), egui::Button::new("Next Level"))
+                                    .clicked()
+                                {
+                                    Some(WonChoice::Next)
+                                } else {
+                                    None
+                                }
+                            })
+                            .and_then(|elem| elem.inner)
+                            .flatten();
+
+                        match choice {
+                            Some(WonChoice::Retry) => match state.retry() {
+                                Ok(state) => *self = Self::play(state, levels.clone()),
+                                Err(err) => error!("{}", err),
+                            },
+                            Some(WonChoice::Exit) => *self = Self::open_level_selection(),
+                            Some(WonChoice::Next) => {
+                                if let Some(next) = next {
+                                    *self = Self::start_level(next, levels.clone());
+                                }
                             }
-                        });
+                            None => {}
+                        }
 
                         UpdateAction::Nothing
                     }
                     GameState::GameState {
-                        show_hud, rotation, ..
+                        show_hud,
+                        rotation,
+                        moves,
+                        position,
+                        time,
+                        hints,
+                        hint,
+                        history,
+                        ..
                     } => {
                         // FIXME should be F1, but egui in the version used be nannou_egui does not have that key
                         if ctx.input(|state| state.key_pressed(Key::H)) {
@@ -180,6 +376,33 @@ impl MenuState {
                         if *show_hud {
                             egui::Window::new("").show(ctx, |ui| {
                                 ui.label("HUD");
+                                if replayer.is_some() {
+                                    ui.label("Watching a replay");
+                                }
+                                ui.label(format!("Time: {:.1}s", time));
+                                ui.label(format!("Moves: {}", moves));
+                                ui.label(format!("Floor: {}", position.z));
+                                ui.label(format!(
+                                    "Undo (Z): {}, Redo (Y): {}",
+                                    history.undo_len(),
+                                    history.redo_len()
+                                ));
+                                ui.label(format!("Hints (Q): {}", hints));
+                                if hint_search.is_some() {
+                                    ui.label("Searching for a hint");
+                                } else if let Some(hint) = hint {
+                                    ui.label(match hint {
+                                        Hint::Next { .. } => "Follow the highlighted tiles",
+                                        Hint::Stuck => {
+                                            "The goal can not be reached, undo some steps"
+                                        }
+                                        Hint::Unknown => "No hint found",
+                                    });
+                                }
+                                ui.separator();
+                                for event in recent_events.iter() {
+                                    ui.label(format!("{:?}", event));
+                                }
                             });
                         }
 
@@ -194,14 +417,50 @@ impl MenuState {
                         key_map.insert(Key::S, Action::Down);
                         key_map.insert(Key::D, Action::Right);
 
-                        ctx.input(|input_state| {
+                        let inputs: Vec<Action> = ctx.input(|input_state| {
                             key_map
                                 .iter()
                                 .filter(|(&k, _)| input_state.key_down(k))
-                                .for_each(|(_, action)| action.perform(state));
+                                .map(|(_, action)| *action)
+                                .collect()
                         });
 
-                        state.handle_input();
+                        if replayer.is_none() {
+                            if ctx.input(|state| state.key_pressed(Key::Z)) {
+                                state.undo();
+                            }
+                            if ctx.input(|state| state.key_pressed(Key::Y)) {
+                                state.redo();
+                            }
+                            if hint_search.is_none() && ctx.input(|state| state.key_pressed(Key::Q))
+                            {
+                                *hint_search = state.request_hint();
+                            }
+                        }
+                        if hint_search
+                            .as_ref()
+                            .is_some_and(|search| search.poll(state))
+                        {
+                            *hint_search = None;
+                        }
+
+                        // drop time that can not be caught up, e.g. after the window was moved
+                        *lag = (*lag + delta).min(MAX_LAG);
+                        let mut logger = |event: &GameEvent| debug!("{:?}", event);
+                        while *lag >= TICK {
+                            let events = match replayer {
+                                Some(replayer) => replayer.tick(state).unwrap_or_default(),
+                                None => state.tick(TICK, &inputs),
+                            };
+                            for event in events {
+                                let subscribers: [&mut dyn Subscriber; 2] =
+                                    [recent_events, &mut logger];
+                                for subscriber in subscribers {
+                                    subscriber.notify(&event);
+                                }
+                            }
+                            *lag -= TICK;
+                        }
 
                         UpdateAction::Nothing
                     }
@@ -0,0 +1,64 @@
+mod common;
+
+use common::NON_TILE_TEXTURES;
+use derive_macros_helpers::{AssetName, Bounded, Enumerable};
+use learning_conrod_core::textures::identify_textures;
+use learning_conrod_game::game::*;
+use std::collections::BTreeSet;
+use std::fmt::Debug;
+use std::path::Path;
+
+fn assert_round_trip<T>()
+where
+    T: AssetName + Enumerable + Bounded + PartialEq + Debug,
+    T::Err: Debug,
+{
+    for value in T::enumerate_all() {
+        assert_eq!(value.to_string().parse::<T>().unwrap(), value);
+    }
+    let names = T::asset_names();
+    let unique: BTreeSet<_> = names.iter().collect();
+    assert_eq!(unique.len(), names.len(), "duplicate names in {:?}", names);
+}
+
+#[test]
+fn names_round_trip() {
+    assert_round_trip::<Direction>();
+    assert_round_trip::<NorthSouthAxis>();
+    assert_round_trip::<EastWestAxis>();
+    assert_round_trip::<Orientation>();
+    assert_round_trip::<WallType>();
+    assert_round_trip::<TileTextureIndex>();
+}
+
+#[test]
+fn file_names_parse_to_their_tile() {
+    assert_eq!(
+        "wall_inner_corner_top_left".parse(),
+        Ok(TileTextureIndex::Wall {
+            kind: WallType::InnerCorner {
+                north_south_facing: NorthSouthAxis::South,
+                east_west_facing: EastWestAxis::West,
+            }
+        })
+    );
+    assert_eq!(
+        "closed_gate_upper".parse(),
+        Ok(TileTextureIndex::Gate {
+            open: false,
+            facing: Direction::DOWN,
+        })
+    );
+    assert_eq!(
+        "goal_inactive".parse(),
+        Ok(TileTextureIndex::Goal { active: false })
+    );
+    assert!("wall_corner_top".parse::<TileTextureIndex>().is_err());
+}
+
+#[test]
+fn every_texture_names_a_tile() {
+    let textures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets/textures");
+    let textures = identify_textures::<TileTextureIndex>(&textures).unwrap();
+    assert_eq!(textures.orphans, NON_TILE_TEXTURES);
+}
@@ -0,0 +1,58 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::PLAYER_EXTENT;
+
+#[test]
+fn walls_stop_the_player_flush() {
+    let mut state = game(tile_map(&["###", "#..", "###"]), (1, 1));
+
+    state.move_player(0.0, -0.4);
+    assert_close(position(&state).1, 0.5 + PLAYER_EXTENT);
+
+    state.move_player(-0.4, 0.0);
+    assert_close(position(&state).0, 0.5 + PLAYER_EXTENT);
+
+    state.move_player(0.3, 0.0);
+    assert_close(position(&state).0, 0.8 + PLAYER_EXTENT);
+}
+
+#[test]
+fn missing_tiles_are_solid() {
+    let mut state = game(tile_map(&["   ", " ..", "   "]), (1, 1));
+
+    state.move_player(5.0, 0.0);
+    assert_close(position(&state).0, 2.5 - PLAYER_EXTENT);
+
+    state.move_player(0.0, 5.0);
+    assert_close(position(&state).1, 1.5 - PLAYER_EXTENT);
+}
+
+#[test]
+fn axes_are_resolved_separately() {
+    let mut state = game(tile_map(&["####", "#...", "####"]), (1, 1));
+
+    // sliding along the wall keeps the movement along it
+    state.move_player(1.0, 1.0);
+    assert_close(position(&state).0, 2.0);
+    assert_close(position(&state).1, 1.5 - PLAYER_EXTENT);
+}
+
+#[test]
+fn closed_gates_block_and_open_gates_do_not() {
+    let mut closed = game(tile_map(&["###", "#.g.", "###"]), (1, 1));
+    closed.move_player(2.0, 0.0);
+    assert_close(position(&closed).0, 1.5 - PLAYER_EXTENT);
+
+    let mut open = game(tile_map(&["####", "#.G.", "####"]), (1, 1));
+    open.move_player(2.0, 0.0);
+    assert_close(position(&open).0, 3.0);
+}
+
+#[test]
+fn large_steps_do_not_tunnel_through_walls() {
+    let mut state = game(tile_map(&["#####", "#.#..", "#####"]), (1, 1));
+
+    state.move_player(2.0, 0.0);
+    assert_close(position(&state).0, 1.5 - PLAYER_EXTENT);
+}
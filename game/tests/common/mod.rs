@@ -0,0 +1,194 @@
+#![allow(dead_code)]
+
+use learning_conrod_game::game::*;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+/// The number of ticks a move takes
+pub const MOVE_TICKS: usize = (1.0 / (DEFAULT_PLAYER_SPEED * TICK)) as usize + 1;
+
+/// Textures in the asset directory that are not tile textures
+pub const NON_TILE_TEXTURES: [&str; 3] = ["shark_left", "shark_up", "test"];
+
+pub fn coord(x: i64, y: i64) -> ObjectCoordinate {
+    ObjectCoordinate { x, y, z: 0 }
+}
+
+/// Tiles from rows of characters, the first row is at `y = 0` and the first column at `x = 0`.
+///
+/// `#` is a wall, `.` a path, `H` a ladder, `g`/`G` a closed/open gate, `^`, `>`, `v` and `<` one-ways
+/// facing north, east, south and west, everything else is left out.
+pub fn tile_map(rows: &[&str]) -> BTreeMap<ObjectCoordinate, TileType> {
+    floor(rows, 0)
+}
+
+/// Like [`tile_map`] but on the floor `z`
+pub fn floor(rows: &[&str], z: i64) -> BTreeMap<ObjectCoordinate, TileType> {
+    let mut tile_map = BTreeMap::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, tile) in row.chars().enumerate() {
+            let gate = |open| TileType::Gate {
+                open,
+                facing: Direction::NORTH,
+                hidden: GateVisibility::Visible,
+                reveal: Reveal::WhenOpened,
+            };
+            let one_way = |facing| TileType::OneWay {
+                inverted: false,
+                facing,
+            };
+            let tile = match tile {
+                '#' => TileType::Wall {
+                    kind: WallType::Center,
+                },
+                '.' => TileType::Path,
+                'H' => TileType::Ladder,
+                'g' => gate(false),
+                'G' => gate(true),
+                '^' => one_way(Direction::NORTH),
+                '>' => one_way(Direction::EAST),
+                'v' => one_way(Direction::SOUTH),
+                '<' => one_way(Direction::WEST),
+                _ => continue,
+            };
+            let coord = ObjectCoordinate {
+                x: x as i64,
+                y: y as i64,
+                z,
+            };
+            tile_map.insert(coord, tile);
+        }
+    }
+    tile_map
+}
+
+/// A game of `tile_map` with the player starting on the tile at `start`,
+/// which is replaced by a `Start` tile
+pub fn game(tile_map: BTreeMap<ObjectCoordinate, TileType>, start: (i64, i64)) -> GameState {
+    game_with(tile_map, start, MovementMode::Continuous)
+}
+
+pub fn game_with(
+    mut tile_map: BTreeMap<ObjectCoordinate, TileType>,
+    (x, y): (i64, i64),
+    movement: MovementMode,
+) -> GameState {
+    tile_map.insert(ObjectCoordinate { x, y, z: 0 }, TileType::Start);
+    GameState::new(Rc::new(template(tile_map, movement))).unwrap()
+}
+
+pub fn template(
+    tile_map: BTreeMap<ObjectCoordinate, TileType>,
+    movement: MovementMode,
+) -> LevelTemplate {
+    LevelTemplate {
+        name: "Test".to_string(),
+        init_state: LevelState {
+            tile_map,
+            wiring: Default::default(),
+        },
+        player_speed: DEFAULT_PLAYER_SPEED,
+        movement,
+        spawn_facing: Direction::SOUTH,
+    }
+}
+
+/// A button at `(1, 0)` toggling the gate below the start at `(0, 0)`
+pub fn button_level(movement: MovementMode) -> Rc<LevelTemplate> {
+    let mut tile_map = tile_map(&["...", "g"]);
+    tile_map.insert(coord(0, 0), TileType::Start);
+    tile_map.insert(
+        coord(1, 0),
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![coord(0, 1)],
+        },
+    );
+    Rc::new(template(tile_map, movement))
+}
+
+/// A button at `(2, 0)` opening the gate in front of the goal at `(2, 2)`
+pub fn gated_goal() -> BTreeMap<ObjectCoordinate, TileType> {
+    let mut tile_map = tile_map(&["...", ".#", ".g."]);
+    tile_map.insert(coord(2, 2), TileType::Goal { active: true });
+    tile_map.insert(
+        coord(2, 0),
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![coord(1, 2)],
+        },
+    );
+    tile_map
+}
+
+/// Ticks `ticks` times with the `inputs` held down
+pub fn run(state: &mut GameState, ticks: usize, inputs: &[Action]) {
+    for _ in 0..ticks {
+        state.tick(TICK, inputs);
+    }
+}
+
+/// Taps the `actions` one tile at a time, returns the events of all ticks
+pub fn play(state: &mut GameState, actions: &[Action]) -> Vec<GameEvent> {
+    let mut events = vec![];
+    for action in actions {
+        events.extend(state.tick(TICK, &[*action]));
+        for _ in 0..MOVE_TICKS {
+            events.extend(state.tick(TICK, &[]));
+        }
+    }
+    events
+}
+
+/// Taps `action` and waits until the move is done
+pub fn step(state: &mut GameState, action: Action) {
+    play(state, &[action]);
+}
+
+pub fn position(state: &GameState) -> (f32, f32) {
+    match state {
+        GameState::GameState { position, .. } => (position.x, position.y),
+        GameState::Won { .. } => panic!("the level should not be won"),
+    }
+}
+
+pub fn floor_of(state: &GameState) -> i64 {
+    match state {
+        GameState::GameState { position, .. } => position.z,
+        GameState::Won { .. } => panic!("the level should not be won"),
+    }
+}
+
+pub fn moves(state: &GameState) -> u32 {
+    match state {
+        GameState::GameState { moves, .. } => *moves,
+        GameState::Won { .. } => panic!("the level should not be won"),
+    }
+}
+
+pub fn level_state(state: &GameState) -> &LevelState {
+    match state {
+        GameState::GameState { level_state, .. } => level_state,
+        GameState::Won { .. } => panic!("the level should not be won"),
+    }
+}
+
+pub fn tile(state: &GameState, x: i64, y: i64) -> &TileType {
+    &level_state(state).tile_map[&ObjectCoordinate { x, y, z: 0 }]
+}
+
+pub fn gate_open(level_state: &LevelState, gate: ObjectCoordinate) -> bool {
+    matches!(
+        level_state.tile_map[&gate],
+        TileType::Gate { open: true, .. }
+    )
+}
+
+pub fn assert_close(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 1e-4,
+        "expected {expected} but was {actual}"
+    );
+}
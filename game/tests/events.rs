@@ -0,0 +1,179 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+/// A button at `(1, 0)` toggling the gate below the start, a pressure plate at `(2, 0)`
+/// switching the same gate and an active goal at `(3, 0)`
+fn level() -> GameState {
+    let mut tile_map = tile_map(&["....", "g"]);
+    tile_map.insert(
+        coord(1, 0),
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![coord(0, 1)],
+        },
+    );
+    tile_map.insert(
+        coord(2, 0),
+        TileType::PressurePlate {
+            inverted: false,
+            targets: vec![coord(0, 1)],
+            occupied: false,
+        },
+    );
+    tile_map.insert(coord(3, 0), TileType::Goal { active: true });
+    game_with(tile_map, (0, 0), MovementMode::Grid)
+}
+
+#[test]
+fn scripted_inputs_produce_the_event_stream() {
+    let mut state = level();
+
+    let events = play(
+        &mut state,
+        &[Action::Right, Action::Right, Action::Right, Action::Right],
+    );
+
+    assert_eq!(
+        events,
+        [
+            GameEvent::PlayerMoved {
+                from: coord(0, 0),
+                to: coord(1, 0),
+            },
+            GameEvent::ButtonToggled {
+                button: coord(1, 0),
+                power: true,
+            },
+            GameEvent::TargetPowered {
+                target: coord(0, 1),
+                power: true,
+            },
+            GameEvent::PlayerMoved {
+                from: coord(1, 0),
+                to: coord(2, 0),
+            },
+            GameEvent::ButtonToggled {
+                button: coord(2, 0),
+                power: true,
+            },
+            GameEvent::TargetPowered {
+                target: coord(0, 1),
+                power: true,
+            },
+            GameEvent::PlayerMoved {
+                from: coord(2, 0),
+                to: coord(3, 0),
+            },
+            GameEvent::ButtonToggled {
+                button: coord(2, 0),
+                power: false,
+            },
+            // the plate switching the gate back is not processed once the level is won
+            GameEvent::GoalReached { goal: coord(3, 0) },
+        ]
+    );
+    assert!(matches!(state, GameState::Won { .. }));
+}
+
+#[test]
+fn ladders_queue_the_climb() {
+    let mut tile_map = tile_map(&["H."]);
+    tile_map.extend(floor(&["H"], 1));
+    let mut state = game_with(tile_map, (1, 0), MovementMode::Grid);
+
+    let events = play(&mut state, &[Action::Left]);
+
+    let ladder = coord(0, 0);
+    let above = ObjectCoordinate { z: 1, ..ladder };
+    assert_eq!(
+        events,
+        [
+            GameEvent::PlayerMoved {
+                from: coord(1, 0),
+                to: ladder,
+            },
+            GameEvent::LadderEntered { ladder },
+            GameEvent::PlayerMoved {
+                from: ladder,
+                to: above,
+            },
+            GameEvent::LadderEntered { ladder: above },
+        ]
+    );
+}
+
+#[test]
+fn subscribers_observe_the_events() {
+    let mut state = level();
+    let mut moves = 0;
+    let mut count_moves = |event: &GameEvent| {
+        if let GameEvent::PlayerMoved { .. } = event {
+            moves += 1;
+        }
+    };
+    let mut log = EventLog::default();
+
+    for event in play(&mut state, &[Action::Right, Action::Right]) {
+        let subscribers: [&mut dyn Subscriber; 2] = [&mut count_moves, &mut log];
+        for subscriber in subscribers {
+            subscriber.notify(&event);
+        }
+    }
+
+    assert_eq!(moves, 2);
+    assert_eq!(log.iter().count(), EventLog::CAPACITY);
+    assert_eq!(
+        log.iter().last(),
+        Some(&GameEvent::TargetPowered {
+            target: coord(0, 1),
+            power: true,
+        })
+    );
+}
+
+#[test]
+fn wiring_outputs_queue_events_when_they_switch() {
+    let mut tile_map = tile_map(&["..", "g"]);
+    tile_map.insert(
+        coord(1, 0),
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![],
+        },
+    );
+    tile_map.insert(coord(0, 0), TileType::Start);
+    let mut level = template(tile_map, MovementMode::Grid);
+    level.init_state.wiring.outputs.push(wiring::Output {
+        signal: wiring::Signal::Tile(coord(1, 0)),
+        target: coord(0, 1),
+    });
+    let mut state = GameState::new(std::rc::Rc::new(level)).unwrap();
+
+    let events = play(&mut state, &[Action::Right]);
+
+    assert_eq!(
+        events,
+        [
+            GameEvent::PlayerMoved {
+                from: coord(0, 0),
+                to: coord(1, 0),
+            },
+            GameEvent::ButtonToggled {
+                button: coord(1, 0),
+                power: true,
+            },
+            GameEvent::TargetPowered {
+                target: coord(0, 1),
+                power: true,
+            },
+        ]
+    );
+    assert!(matches!(
+        tile(&state, 0, 1),
+        TileType::Gate { open: true, .. }
+    ));
+}
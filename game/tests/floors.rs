@@ -0,0 +1,89 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+use std::collections::BTreeMap;
+
+/// A ladder at `(0, 0)` on the ground floor with `above` on top of it,
+/// the player starts next to the ladder
+fn tower(above: &str) -> GameState {
+    let mut tile_map: BTreeMap<_, _> = tile_map(&["H."]);
+    tile_map.extend(floor(&[&format!("{}.", above)], 1));
+    game_with(tile_map, (1, 0), MovementMode::Grid)
+}
+
+#[test]
+fn coordinates_without_a_floor_are_on_the_ground_floor() {
+    let coord: ObjectCoordinate = ron::from_str("(x: 3, y: 4)").unwrap();
+    assert_eq!(coord, ObjectCoordinate { x: 3, y: 4, z: 0 });
+}
+
+#[test]
+fn ladders_climb_up_without_turning_back() {
+    let mut state = tower("H");
+
+    step(&mut state, Action::Left);
+    assert_eq!(floor_of(&state), 1);
+    assert_eq!(position(&state), (0.0, 0.0));
+
+    step(&mut state, Action::Right);
+    assert_eq!(floor_of(&state), 1);
+    assert_eq!(position(&state), (1.0, 0.0));
+}
+
+#[test]
+fn ladders_climb_down_when_entered_from_above() {
+    let mut state = tower("H");
+
+    step(&mut state, Action::Left);
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Left);
+    assert_eq!(floor_of(&state), 0);
+
+    step(&mut state, Action::Right);
+    assert_eq!(floor_of(&state), 0);
+    assert_eq!(position(&state), (1.0, 0.0));
+}
+
+#[test]
+fn closed_gates_block_climbing() {
+    let mut state = tower("g");
+    step(&mut state, Action::Left);
+    assert_eq!(floor_of(&state), 0);
+
+    let mut state = tower("G");
+    step(&mut state, Action::Left);
+    assert_eq!(floor_of(&state), 1);
+}
+
+#[test]
+fn one_ways_facing_up_or_down_work_vertically() {
+    let one_way = |facing| {
+        let mut state = tower(".");
+        if let GameState::GameState { level_state, .. } = &mut state {
+            level_state.tile_map.insert(
+                ObjectCoordinate { x: 0, y: 0, z: 1 },
+                TileType::OneWay {
+                    inverted: false,
+                    facing,
+                },
+            );
+        }
+        step(&mut state, Action::Left);
+        floor_of(&state)
+    };
+
+    assert_eq!(one_way(Direction::UP), 1);
+    assert_eq!(one_way(Direction::DOWN), 0);
+}
+
+#[test]
+fn only_the_floor_of_the_player_collides() {
+    let mut tile_map = tile_map(&["..."]);
+    tile_map.extend(floor(&[".#."], 1));
+    let mut state = game(tile_map, (0, 0));
+
+    state.tick(1.0, &[Action::Right]);
+
+    assert_close(position(&state).0, 2.0);
+}
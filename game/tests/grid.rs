@@ -0,0 +1,84 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+fn grid(rows: &[&str], start: (i64, i64)) -> GameState {
+    game_with(tile_map(rows), start, MovementMode::Grid)
+}
+
+#[test]
+fn a_tap_moves_exactly_one_tile() {
+    let mut state = grid(&["...."], (0, 0));
+
+    state.tick(TICK, &[Action::Right]);
+    run(&mut state, MOVE_TICKS * 3, &[]);
+
+    assert_eq!(position(&state), (1.0, 0.0));
+    assert_eq!(moves(&state), 1);
+}
+
+#[test]
+fn holding_repeats_moves() {
+    let mut state = grid(&["...."], (0, 0));
+
+    run(&mut state, MOVE_TICKS * 2, &[Action::Right]);
+    run(&mut state, MOVE_TICKS, &[]);
+
+    assert_eq!(position(&state), (2.0, 0.0));
+    assert_eq!(moves(&state), 2);
+}
+
+#[test]
+fn moves_are_tweened() {
+    let mut state = grid(&[".."], (0, 0));
+
+    run(&mut state, MOVE_TICKS / 2, &[Action::Right]);
+
+    let (x, y) = position(&state);
+    assert!(0.0 < x && x < 1.0, "{x} should be between the tiles");
+    assert_eq!(y, 0.0);
+}
+
+#[test]
+fn solid_tiles_are_not_entered() {
+    let mut state = grid(&[".#", "g"], (0, 0));
+
+    run(&mut state, MOVE_TICKS, &[Action::Right]);
+    run(&mut state, MOVE_TICKS, &[Action::Down]);
+    run(&mut state, MOVE_TICKS, &[Action::Up]);
+
+    assert_eq!(position(&state), (0.0, 0.0));
+    assert_eq!(moves(&state), 0);
+}
+
+#[test]
+fn each_move_steps_on_its_target_once() {
+    let mut tile_map = tile_map(&["..", "g"]);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![ObjectCoordinate { x: 0, y: 1, z: 0 }],
+        },
+    );
+    let mut state = game_with(tile_map, (0, 0), MovementMode::Grid);
+
+    state.tick(TICK, &[Action::Right]);
+    run(&mut state, MOVE_TICKS, &[]);
+    assert!(matches!(
+        tile(&state, 1, 0),
+        TileType::Button { pressed: true, .. }
+    ));
+
+    state.tick(TICK, &[Action::Left]);
+    run(&mut state, MOVE_TICKS, &[]);
+    state.tick(TICK, &[Action::Right]);
+    run(&mut state, MOVE_TICKS, &[]);
+    assert!(matches!(
+        tile(&state, 1, 0),
+        TileType::Button { pressed: false, .. }
+    ));
+    assert_eq!(moves(&state), 3);
+}
@@ -0,0 +1,141 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+const WALL: TileType = TileType::Wall {
+    kind: WallType::Center,
+};
+
+fn hidden_gate(open: bool, mimic: TileType, reveal: Reveal) -> TileType {
+    TileType::Gate {
+        open,
+        facing: Direction::NORTH,
+        hidden: GateVisibility::Hidden(Box::new(mimic)),
+        reveal,
+    }
+}
+
+fn gate_texture(open: bool) -> TileTextureIndex {
+    TileTextureIndex::Gate {
+        open,
+        facing: Direction::NORTH,
+    }
+}
+
+fn wall_texture() -> TileTextureIndex {
+    TileTextureIndex::Wall {
+        kind: WallType::Center,
+    }
+}
+
+/// Three paths followed by `gate`, the player starts on the first path
+fn corridor(gate: TileType) -> GameState {
+    let mut tile_map = tile_map(&["..."]);
+    tile_map.insert(ObjectCoordinate { x: 3, y: 0, z: 0 }, gate);
+    game_with(tile_map, (0, 0), MovementMode::Grid)
+}
+
+#[test]
+fn visible_gates_look_like_gates() {
+    let gate = TileType::Gate {
+        open: false,
+        facing: Direction::NORTH,
+        hidden: GateVisibility::Visible,
+        reveal: Reveal::WhenOpened,
+    };
+    assert_eq!(gate.tile_texture_id(), gate_texture(false));
+}
+
+#[test]
+fn hidden_gates_look_like_their_mimic_while_closed() {
+    let closed = hidden_gate(false, WALL, Reveal::WhenOpened);
+    assert_eq!(closed.tile_texture_id(), wall_texture());
+
+    let open = hidden_gate(true, WALL, Reveal::WhenAdjacent);
+    assert_eq!(open.tile_texture_id(), gate_texture(true));
+}
+
+#[test]
+fn gates_revealed_when_opened_stay_visible() {
+    let mut gate = hidden_gate(false, WALL, Reveal::WhenOpened);
+
+    gate.apply_button(true);
+    gate.apply_button(false);
+
+    assert_eq!(gate.tile_texture_id(), gate_texture(false));
+}
+
+#[test]
+fn gates_revealed_when_adjacent_hide_again_when_closed() {
+    let mut gate = hidden_gate(false, WALL, Reveal::WhenAdjacent);
+
+    gate.apply_button(true);
+    gate.apply_button(false);
+
+    assert_eq!(gate.tile_texture_id(), wall_texture());
+}
+
+#[test]
+fn gates_revealed_when_adjacent_are_revealed_next_to_the_player() {
+    let mut state = corridor(hidden_gate(false, WALL, Reveal::WhenAdjacent));
+
+    step(&mut state, Action::Right);
+    assert_eq!(tile(&state, 3, 0).tile_texture_id(), wall_texture());
+
+    step(&mut state, Action::Right);
+    assert_eq!(tile(&state, 3, 0).tile_texture_id(), gate_texture(false));
+}
+
+#[test]
+fn gates_next_to_the_start_are_revealed_at_once() {
+    let mut tile_map = tile_map(&["."]);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        hidden_gate(false, WALL, Reveal::WhenAdjacent),
+    );
+    let state = game(tile_map, (0, 0));
+
+    assert_eq!(tile(&state, 1, 0).tile_texture_id(), gate_texture(false));
+}
+
+#[test]
+fn gates_can_not_mimic_gates_or_buttons() {
+    let validate = |mimic| {
+        let mut tile_map = tile_map(&["."]);
+        tile_map.insert(ObjectCoordinate { x: 0, y: 0, z: 0 }, TileType::Start);
+        tile_map.insert(
+            ObjectCoordinate { x: 1, y: 0, z: 0 },
+            hidden_gate(false, mimic, Reveal::WhenOpened),
+        );
+        template(tile_map, MovementMode::Grid).validate()
+    };
+    let invalid = Err(ValidationError::InvalidMimic(ObjectCoordinate {
+        x: 1,
+        y: 0,
+        z: 0,
+    }));
+
+    assert_eq!(validate(TileType::Path), Ok(()));
+    assert_eq!(validate(WALL), Ok(()));
+    assert_eq!(
+        validate(hidden_gate(false, WALL, Reveal::WhenOpened)),
+        invalid
+    );
+    assert_eq!(
+        validate(TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![],
+        }),
+        invalid
+    );
+    assert_eq!(
+        validate(TileType::PressurePlate {
+            inverted: false,
+            targets: vec![],
+            occupied: false,
+        }),
+        invalid
+    );
+}
@@ -0,0 +1,95 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+fn request_hint(state: &mut GameState) {
+    state.request_hint().unwrap().wait(state);
+}
+
+#[test]
+fn hints_point_at_the_next_tile_and_button() {
+    let mut state = game_with(gated_goal(), (1, 0), MovementMode::Grid);
+
+    request_hint(&mut state);
+
+    assert_eq!(
+        state.hint(),
+        Some(&Hint::Next {
+            tile: coord(2, 0),
+            button: Some(coord(2, 0)),
+        })
+    );
+
+    // once the button is pressed only the way is left
+    play(&mut state, &[Action::Right]);
+    assert_eq!(state.hint(), None);
+    request_hint(&mut state);
+
+    assert_eq!(
+        state.hint(),
+        Some(&Hint::Next {
+            tile: coord(1, 0),
+            button: None,
+        })
+    );
+}
+
+#[test]
+fn unreachable_goals_give_a_stuck_hint() {
+    let mut tile_map = tile_map(&[".g."]);
+    tile_map.insert(coord(2, 0), TileType::Goal { active: true });
+    let mut state = game_with(tile_map, (0, 0), MovementMode::Grid);
+
+    request_hint(&mut state);
+
+    assert_eq!(state.hint(), Some(&Hint::Stuck));
+}
+
+#[test]
+fn hints_found_after_moving_are_dropped() {
+    let mut state = game_with(gated_goal(), (1, 0), MovementMode::Grid);
+
+    let search = state.request_hint().unwrap();
+    play(&mut state, &[Action::Left]);
+    search.wait(&mut state);
+
+    assert_eq!(state.hint(), None);
+}
+
+#[test]
+fn hints_are_counted_in_the_stats_and_replays() {
+    let mut state = game_with(gated_goal(), (1, 0), MovementMode::Grid);
+
+    request_hint(&mut state);
+    play(&mut state, &[Action::Right]);
+    request_hint(&mut state);
+    play(
+        &mut state,
+        &[
+            Action::Left,
+            Action::Left,
+            Action::Down,
+            Action::Down,
+            Action::Right,
+            Action::Right,
+        ],
+    );
+
+    let GameState::Won {
+        level_template,
+        stats,
+        replay,
+    } = &state
+    else {
+        panic!("the level should be won")
+    };
+    assert_eq!(stats.hints, 2);
+
+    match replay.play(level_template.clone()).unwrap() {
+        GameState::Won {
+            stats: replayed, ..
+        } => assert_eq!(&replayed, stats),
+        GameState::GameState { .. } => panic!("the replay should win the level"),
+    }
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+#[test]
+fn one_ways_are_passed_along_their_facing() {
+    let mut state = game(tile_map(&[".>."]), (0, 0));
+    state.move_player(2.0, 0.0);
+    assert_close(position(&state).0, 2.0);
+
+    // and can not be entered from the other side
+    state.move_player(-2.0, 0.0);
+    assert_close(position(&state).0, 1.5 + PLAYER_EXTENT);
+}
+
+#[test]
+fn one_ways_can_only_be_left_along_their_facing() {
+    let mut state = game(tile_map(&[".>.", ".."]), (0, 0));
+    state.move_player(1.0, 0.0);
+
+    state.move_player(-1.0, 0.0);
+    assert_close(position(&state).0, 0.5 + PLAYER_EXTENT);
+
+    state.move_player(0.0, 1.0);
+    assert_close(position(&state).1, 0.5 - PLAYER_EXTENT);
+}
+
+#[test]
+fn inverted_one_ways_are_passed_against_their_facing() {
+    let mut tile_map = tile_map(&[".>."]);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        TileType::OneWay {
+            inverted: true,
+            facing: Direction::EAST,
+        },
+    );
+    let mut state = game(tile_map, (2, 0));
+
+    state.move_player(-2.0, 0.0);
+    assert_close(position(&state).0, 0.0);
+}
+
+#[test]
+fn buttons_invert_one_ways() {
+    let mut tile_map = tile_map(&["", ".>."]);
+    let one_way = ObjectCoordinate { x: 1, y: 1, z: 0 };
+    tile_map.insert(
+        ObjectCoordinate { x: 2, y: 0, z: 0 },
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![one_way],
+        },
+    );
+    let mut state = game(tile_map, (2, 1));
+
+    // the one-way blocks from the east
+    state.move_player(-1.0, 0.0);
+    assert_close(position(&state).0, 1.5 + PLAYER_EXTENT);
+
+    // until the button flips it
+    state.move_player(0.0, -1.0);
+    state.tick(0.0, &[]);
+    assert!(matches!(
+        tile(&state, 1, 1),
+        TileType::OneWay { inverted: true, .. }
+    ));
+    state.move_player(0.0, 1.0);
+    state.tick(0.0, &[]);
+    state.move_player(-2.0, 0.0);
+    assert_close(position(&state).0, -0.5 + PLAYER_EXTENT);
+
+    // now it blocks from the west
+    state.move_player(2.0, 0.0);
+    assert_close(position(&state).0, 0.5 - PLAYER_EXTENT);
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+const GATE: ObjectCoordinate = ObjectCoordinate { x: 1, y: 1, z: 0 };
+
+/// A row of three paths with `input` in the middle and a closed gate below it
+fn level(input: TileType) -> GameState {
+    let mut tile_map = tile_map(&["...", ".g"]);
+    tile_map.insert(ObjectCoordinate { x: 1, y: 0, z: 0 }, input);
+    game_with(tile_map, (0, 0), MovementMode::Grid)
+}
+
+fn timed_button(duration: f32) -> TileType {
+    TileType::TimedButton {
+        duration,
+        inverted: false,
+        targets: vec![GATE],
+        remaining: 0.0,
+    }
+}
+
+#[test]
+fn pressure_plate_is_powered_while_occupied() {
+    let mut state = level(TileType::PressurePlate {
+        inverted: false,
+        targets: vec![GATE],
+        occupied: false,
+    });
+
+    step(&mut state, Action::Right);
+    assert!(gate_open(level_state(&state), GATE));
+    assert_eq!(
+        tile(&state, 1, 0).tile_texture_id(),
+        TileTextureIndex::PressurePlate { pressed: true }
+    );
+
+    step(&mut state, Action::Right);
+    assert!(!gate_open(level_state(&state), GATE));
+    assert!(matches!(
+        tile(&state, 1, 0),
+        TileType::PressurePlate {
+            occupied: false,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn inverted_pressure_plate_closes_while_occupied() {
+    let mut state = level(TileType::PressurePlate {
+        inverted: true,
+        targets: vec![GATE],
+        occupied: false,
+    });
+
+    step(&mut state, Action::Right);
+    assert!(!gate_open(level_state(&state), GATE));
+    step(&mut state, Action::Left);
+    assert!(gate_open(level_state(&state), GATE));
+}
+
+#[test]
+fn timed_button_reverts_after_its_duration() {
+    let mut state = level(timed_button(1.0));
+
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Right);
+    assert!(gate_open(level_state(&state), GATE));
+
+    // the move off the button already used up MOVE_TICKS of the duration
+    run(&mut state, (1.0 / TICK) as usize - MOVE_TICKS - 2, &[]);
+    assert!(gate_open(level_state(&state), GATE));
+    run(&mut state, 3, &[]);
+    assert!(!gate_open(level_state(&state), GATE));
+    assert_eq!(
+        tile(&state, 1, 0).tile_texture_id(),
+        TileTextureIndex::TimedButton { pressed: false }
+    );
+}
+
+#[test]
+fn stepping_on_a_timed_button_again_restarts_it() {
+    let mut state = level(timed_button(1.0));
+
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Left);
+    run(&mut state, (1.0 / TICK) as usize - MOVE_TICKS, &[]);
+
+    assert!(gate_open(level_state(&state), GATE));
+    assert!(matches!(
+        tile(&state, 1, 0),
+        TileType::TimedButton { remaining, .. } if *remaining > 0.0
+    ));
+}
+
+#[test]
+fn plates_must_target_switchable_tiles() {
+    let mut tile_map = tile_map(&["..."]);
+    tile_map.insert(ObjectCoordinate { x: 0, y: 0, z: 0 }, TileType::Start);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        TileType::PressurePlate {
+            inverted: false,
+            targets: vec![ObjectCoordinate { x: 2, y: 0, z: 0 }],
+            occupied: false,
+        },
+    );
+
+    assert_eq!(
+        template(tile_map, MovementMode::Grid).validate(),
+        Err(ValidationError::InvalidTarget {
+            source: ObjectCoordinate { x: 1, y: 0, z: 0 },
+            target: ObjectCoordinate { x: 2, y: 0, z: 0 },
+        })
+    );
+}
+
+#[test]
+fn timed_buttons_must_count_down_a_valid_duration() {
+    for duration in [-1.0, f32::NAN, f32::INFINITY] {
+        let mut tile_map = tile_map(&["...", ".g"]);
+        tile_map.insert(ObjectCoordinate { x: 0, y: 0, z: 0 }, TileType::Start);
+        tile_map.insert(
+            ObjectCoordinate { x: 1, y: 0, z: 0 },
+            timed_button(duration),
+        );
+
+        assert_eq!(
+            template(tile_map, MovementMode::Grid).validate(),
+            Err(ValidationError::InvalidDuration(ObjectCoordinate {
+                x: 1,
+                y: 0,
+                z: 0
+            }))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::replay::*;
+use learning_conrod_game::game::test_level::test_level;
+use learning_conrod_game::game::*;
+use std::path::Path;
+use std::rc::Rc;
+
+#[test]
+fn replaying_a_recording_gives_the_same_state() {
+    let level = button_level(MovementMode::Continuous);
+    let mut state = GameState::new(level.clone()).unwrap();
+    for _ in 0..40 {
+        state.tick(TICK, &[Action::Right]);
+    }
+    state.undo();
+    for _ in 0..20 {
+        state.tick(TICK, &[Action::Right, Action::Down]);
+    }
+    state.undo();
+    state.redo();
+    state.tick(TICK, &[]);
+
+    let replayed = state.replay().play(level).unwrap();
+
+    assert_eq!(level_state(&replayed), level_state(&state));
+    assert_eq!(position(&replayed), position(&state));
+    assert_eq!(moves(&replayed), moves(&state));
+    assert_eq!(replayed.replay(), state.replay());
+}
+
+#[test]
+fn replays_are_only_played_on_their_level() {
+    let mut state = GameState::new(button_level(MovementMode::Continuous)).unwrap();
+    state.tick(TICK, &[Action::Right]);
+
+    let mut other = (*button_level(MovementMode::Continuous)).clone();
+    other.player_speed *= 2.0;
+
+    assert!(matches!(
+        state.replay().play(Rc::new(other)),
+        Err(ReplayError::LevelMismatch { .. })
+    ));
+}
+
+#[test]
+fn checked_in_replays_win_their_level() {
+    let levels = [test_level()];
+    let replays = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/replays");
+    let mut played = 0;
+
+    for entry in replays.read_dir().unwrap() {
+        let path = entry.unwrap().path();
+        let replay = Replay::load(&path).unwrap_or_else(|_| panic!("{:?} should load", path));
+        let level = levels
+            .iter()
+            .find(|level| level_hash(level) == replay.level_hash)
+            .unwrap_or_else(|| panic!("the level of {:?} has changed", path));
+
+        let state = replay.play(Rc::new(level.clone())).unwrap();
+
+        assert!(
+            matches!(state, GameState::Won { .. }),
+            "{:?} should win",
+            path
+        );
+        played += 1;
+    }
+    assert!(played > 0);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::solver::*;
+use learning_conrod_game::game::test_level::test_level;
+use learning_conrod_game::game::*;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+const N: Direction = Direction::NORTH;
+const E: Direction = Direction::EAST;
+const S: Direction = Direction::SOUTH;
+const W: Direction = Direction::WEST;
+
+fn action(direction: Direction) -> Action {
+    match direction {
+        Direction::NORTH => Action::Up,
+        Direction::SOUTH => Action::Down,
+        Direction::EAST => Action::Right,
+        Direction::WEST => Action::Left,
+        _ => panic!("the solver only moves horizontally"),
+    }
+}
+
+fn solve_level(tile_map: BTreeMap<ObjectCoordinate, TileType>, start: (i64, i64)) -> Solution {
+    solve(&game_with(tile_map, start, MovementMode::Grid))
+}
+
+fn with_tile(
+    mut tile_map: BTreeMap<ObjectCoordinate, TileType>,
+    (x, y): (i64, i64),
+    tile: TileType,
+) -> BTreeMap<ObjectCoordinate, TileType> {
+    tile_map.insert(ObjectCoordinate { x, y, z: 0 }, tile);
+    tile_map
+}
+
+#[test]
+fn the_test_level_is_solved() {
+    let level = test_level();
+
+    let solution = solve(&GameState::new(Rc::new(level.clone())).unwrap());
+
+    assert_eq!(
+        solution,
+        Solution::Solved(vec![W, N, S, E, S, E, E, S, S, W, W])
+    );
+
+    // and the solution wins when played
+    let Solution::Solved(path) = solution else {
+        unreachable!()
+    };
+    let mut state = GameState::new(Rc::new(LevelTemplate {
+        movement: MovementMode::Grid,
+        ..level
+    }))
+    .unwrap();
+    for direction in path {
+        step(&mut state, action(direction));
+    }
+    assert!(matches!(state, GameState::Won { .. }));
+}
+
+#[test]
+fn goals_behind_closed_gates_are_unsolvable() {
+    let tile_map = with_tile(tile_map(&[".g."]), (2, 0), TileType::Goal { active: true });
+
+    assert_eq!(solve_level(tile_map, (0, 0)), Solution::Unsolvable);
+}
+
+#[test]
+fn inactive_goals_without_buttons_are_unsolvable() {
+    let tile_map = with_tile(tile_map(&[".."]), (1, 0), TileType::Goal { active: false });
+
+    assert_eq!(solve_level(tile_map, (0, 0)), Solution::Unsolvable);
+}
+
+#[test]
+fn one_ways_only_lead_one_way() {
+    let goal_east = with_tile(
+        tile_map(&["...>."]),
+        (4, 0),
+        TileType::Goal { active: true },
+    );
+    assert_eq!(solve_level(goal_east, (2, 0)), Solution::Solved(vec![E, E]));
+
+    let goal_west = with_tile(
+        tile_map(&[".>..."]),
+        (0, 0),
+        TileType::Goal { active: true },
+    );
+    assert_eq!(solve_level(goal_west, (2, 0)), Solution::Unsolvable);
+}
+
+#[test]
+fn buttons_are_pressed_on_the_way() {
+    // stepping on the button again closes the gate
+    assert_eq!(
+        solve_level(gated_goal(), (1, 0)),
+        Solution::Solved(vec![E, W, W, S, S, E, E])
+    );
+}
+
+#[test]
+fn ladders_lead_to_other_floors() {
+    let mut tile_map = tile_map(&["H."]);
+    tile_map.extend(floor(&["H."], 1));
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 1 },
+        TileType::Goal { active: true },
+    );
+
+    assert_eq!(solve_level(tile_map, (1, 0)), Solution::Solved(vec![W, E]));
+}
+
+#[test]
+fn won_games_need_no_steps() {
+    let tile_map = with_tile(tile_map(&[".."]), (1, 0), TileType::Goal { active: true });
+    let mut state = game_with(tile_map, (0, 0), MovementMode::Grid);
+    let Solution::Solved(path) = solve(&state) else {
+        panic!("the level should be solvable")
+    };
+    step(&mut state, action(path[0]));
+
+    assert_eq!(solve(&state), Solution::Solved(vec![]));
+}
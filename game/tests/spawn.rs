@@ -0,0 +1,82 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::test_level::test_level;
+use learning_conrod_game::game::*;
+use std::rc::Rc;
+
+#[test]
+fn the_player_spawns_on_the_start_tile() {
+    let mut tile_map = tile_map(&["...", "..."]);
+    tile_map.insert(ObjectCoordinate { x: 2, y: 1, z: 0 }, TileType::Start);
+    let mut level = template(tile_map, MovementMode::Continuous);
+    level.spawn_facing = Direction::WEST;
+
+    match GameState::new(Rc::new(level)).unwrap() {
+        GameState::GameState {
+            position,
+            old_position,
+            facing,
+            ..
+        } => {
+            assert_eq!((position.x, position.y), (2.0, 1.0));
+            assert_eq!(old_position, ObjectCoordinate { x: 2, y: 1, z: 0 });
+            assert_eq!(facing, Direction::WEST);
+        }
+        GameState::Won { .. } => panic!("the level should not be won"),
+    }
+}
+
+#[test]
+fn levels_without_a_start_are_invalid() {
+    let level = template(tile_map(&["..."]), MovementMode::Continuous);
+    assert_eq!(level.validate(), Err(ValidationError::NoStart));
+    assert!(GameState::new(Rc::new(level)).is_err());
+}
+
+#[test]
+fn levels_with_multiple_starts_are_invalid() {
+    let mut tile_map = tile_map(&["..."]);
+    let starts = [
+        ObjectCoordinate { x: 0, y: 0, z: 0 },
+        ObjectCoordinate { x: 2, y: 0, z: 0 },
+    ];
+    for start in starts {
+        tile_map.insert(start, TileType::Start);
+    }
+    let level = template(tile_map, MovementMode::Continuous);
+    assert_eq!(
+        level.validate(),
+        Err(ValidationError::MultipleStarts(starts.to_vec()))
+    );
+}
+
+#[test]
+fn levels_must_move_the_player_forwards() {
+    for speed in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+        let mut tile_map = tile_map(&["..."]);
+        tile_map.insert(ObjectCoordinate { x: 0, y: 0, z: 0 }, TileType::Start);
+        let mut level = template(tile_map, MovementMode::Grid);
+        level.player_speed = speed;
+        assert_eq!(level.validate(), Err(ValidationError::InvalidSpeed));
+    }
+}
+
+#[test]
+fn facing_follows_the_movement() {
+    let mut state = game(tile_map(&["..."]), (1, 0));
+
+    state.tick(TICK, &[Action::Left]);
+    assert!(matches!(
+        state,
+        GameState::GameState {
+            facing: Direction::WEST,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn the_test_level_is_valid() {
+    assert_eq!(test_level().validate(), Ok(()));
+}
@@ -0,0 +1,59 @@
+mod common;
+
+use common::NON_TILE_TEXTURES;
+use derive_macros_helpers::AssetName;
+use learning_conrod_core::textures::texture_coverage;
+use learning_conrod_game::game::TileTextureIndex;
+use std::path::Path;
+
+/// Tiles that are known to have no texture yet, remove entries once their texture is added
+const MISSING_TEXTURES: [&str; 30] = [
+    "wall_single_lower",
+    "wall_single_upper",
+    "wall_double_horizontal",
+    "wall_double_vertical",
+    "wall_end_lower",
+    "wall_end_upper",
+    "wall_end_bottom",
+    "wall_end_left",
+    "wall_end_top",
+    "wall_end_right",
+    "ladder",
+    "goal_inactive",
+    "closed_gate_lower",
+    "closed_gate_upper",
+    "closed_gate_bottom",
+    "closed_gate_left",
+    "closed_gate_top",
+    "closed_gate_right",
+    "open_gate_lower",
+    "open_gate_upper",
+    "open_gate_bottom",
+    "open_gate_left",
+    "open_gate_top",
+    "open_gate_right",
+    "one_way_lower",
+    "one_way_upper",
+    "one_way_bottom",
+    "one_way_left",
+    "one_way_top",
+    "one_way_right",
+];
+
+#[test]
+fn tile_texture_coverage_matches_allow_list() {
+    let textures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets/textures");
+    let coverage = texture_coverage::<TileTextureIndex>(&textures).unwrap();
+
+    let missing: Vec<_> = coverage
+        .missing
+        .iter()
+        .map(|tile| tile.to_string())
+        .collect();
+    assert_eq!(missing, MISSING_TEXTURES);
+    assert_eq!(coverage.unexpected, NON_TILE_TEXTURES);
+    assert_eq!(
+        coverage.present.len() + coverage.missing.len(),
+        TileTextureIndex::asset_names().len()
+    );
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+#[test]
+fn movement_is_in_tiles_per_second() {
+    let mut state = game(tile_map(&["......"]), (0, 0));
+
+    for _ in 0..60 {
+        state.tick(TICK, &[Action::Right]);
+    }
+    assert_close(position(&state).0, DEFAULT_PLAYER_SPEED);
+}
+
+#[test]
+fn movement_does_not_depend_on_the_tick_length() {
+    let mut coarse = game(tile_map(&["......"]), (0, 0));
+    let mut fine = game(tile_map(&["......"]), (0, 0));
+
+    coarse.tick(0.5, &[Action::Right]);
+    for _ in 0..30 {
+        fine.tick(TICK, &[Action::Right]);
+    }
+    assert_close(position(&coarse).0, position(&fine).0);
+}
+
+#[test]
+fn opposite_actions_cancel_out() {
+    let mut state = game(tile_map(&["...", "...", "..."]), (1, 1));
+
+    state.tick(
+        0.5,
+        &[Action::Left, Action::Right, Action::Up, Action::Down],
+    );
+    assert_eq!(position(&state), (1.0, 1.0));
+}
+
+#[test]
+fn ticks_step_on_tiles() {
+    let mut tile_map = tile_map(&[".."]);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        TileType::Goal { active: true },
+    );
+    let mut state = game(tile_map, (0, 0));
+
+    state.tick(0.5, &[Action::Right]);
+    assert!(matches!(state, GameState::Won { .. }));
+}
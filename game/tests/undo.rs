@@ -0,0 +1,70 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::*;
+
+#[test]
+fn undoing_a_button_step_restores_the_gate() {
+    let mut state = GameState::new(button_level(MovementMode::Grid)).unwrap();
+    step(&mut state, Action::Right);
+    assert!(gate_open(level_state(&state), coord(0, 1)));
+
+    assert!(state.undo());
+
+    assert!(!gate_open(level_state(&state), coord(0, 1)));
+    assert!(matches!(
+        tile(&state, 1, 0),
+        TileType::Button { pressed: false, .. }
+    ));
+    assert_eq!(position(&state), (0.0, 0.0));
+    assert_eq!(moves(&state), 0);
+    assert!(!state.undo());
+}
+
+#[test]
+fn undoing_in_continuous_movement_returns_to_the_previous_tile() {
+    let mut state = GameState::new(button_level(MovementMode::Continuous)).unwrap();
+    state.tick(0.4, &[Action::Right]);
+    assert!(gate_open(level_state(&state), coord(0, 1)));
+
+    assert!(state.undo());
+    state.tick(TICK, &[]);
+
+    assert!(!gate_open(level_state(&state), coord(0, 1)));
+    assert_eq!(position(&state), (0.0, 0.0));
+    assert_eq!(moves(&state), 0);
+}
+
+#[test]
+fn redo_steps_again_until_a_new_step_is_taken() {
+    let mut state = GameState::new(button_level(MovementMode::Grid)).unwrap();
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Right);
+    state.undo();
+    state.undo();
+
+    assert!(state.redo());
+    assert!(gate_open(level_state(&state), coord(0, 1)));
+    assert_eq!(position(&state), (1.0, 0.0));
+
+    step(&mut state, Action::Left);
+    assert!(!state.redo());
+    assert_eq!(position(&state), (0.0, 0.0));
+}
+
+#[test]
+fn the_history_is_bounded() {
+    let mut state = game_with(tile_map(&[".."]), (0, 0), MovementMode::Grid);
+    for _ in 0..MAX_HISTORY {
+        step(&mut state, Action::Right);
+        step(&mut state, Action::Left);
+    }
+
+    let mut undone = 0;
+    while state.undo() {
+        undone += 1;
+    }
+
+    assert_eq!(undone, MAX_HISTORY);
+    assert_eq!(moves(&state), MAX_HISTORY as u32);
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::wiring::*;
+use learning_conrod_game::game::*;
+use std::collections::BTreeMap;
+
+const GATE: ObjectCoordinate = ObjectCoordinate { x: 0, y: 1, z: 0 };
+
+/// Buttons in the first row, a closed gate below the first
+fn level(buttons: usize, nodes: Vec<(&str, Node)>, output: Signal) -> LevelState {
+    let mut tile_map = tile_map(&["", "g"]);
+    for x in 0..buttons {
+        tile_map.insert(
+            coord(x as i64, 0),
+            TileType::Button {
+                pressed: false,
+                inverted: false,
+                targets: vec![],
+            },
+        );
+    }
+    let nodes: BTreeMap<_, _> = nodes
+        .into_iter()
+        .map(|(name, node)| (name.to_string(), node))
+        .collect();
+    LevelState {
+        tile_map,
+        wiring: Wiring {
+            nodes,
+            outputs: vec![Output {
+                signal: output,
+                target: GATE,
+            }],
+        },
+    }
+}
+
+fn press(level: &mut LevelState, x: i64, pressed: bool) {
+    if let Some(TileType::Button {
+        pressed: button, ..
+    }) = level.tile_map.get_mut(&coord(x, 0))
+    {
+        *button = pressed;
+    }
+    evaluate(level);
+}
+
+/// Evaluates the wiring and switches the targets of its events
+fn evaluate(level: &mut LevelState) {
+    for event in level.update_wiring() {
+        if let GameEvent::TargetPowered { target, power } = event {
+            level.apply_power(&[target], power);
+        }
+    }
+}
+
+fn node(name: &str) -> Signal {
+    Signal::Node(name.to_string())
+}
+
+fn button(x: i64) -> Signal {
+    Signal::Tile(coord(x, 0))
+}
+
+/// The gate for every combination of two buttons in the order 00, 01, 10, 11
+fn truth_table(node: Node) -> [bool; 4] {
+    let mut level = level(2, vec![("node", node)], self::node("node"));
+    [(false, false), (false, true), (true, false), (true, true)].map(|(a, b)| {
+        press(&mut level, 0, a);
+        press(&mut level, 1, b);
+        gate_open(&level, GATE)
+    })
+}
+
+#[test]
+fn gates_combine_their_inputs() {
+    let inputs = || vec![button(0), button(1)];
+    assert_eq!(
+        truth_table(Node::And(inputs())),
+        [false, false, false, true]
+    );
+    assert_eq!(truth_table(Node::Or(inputs())), [false, true, true, true]);
+    assert_eq!(truth_table(Node::Xor(inputs())), [false, true, true, false]);
+}
+
+#[test]
+fn not_inverts_its_input() {
+    let mut level = level(1, vec![("not", Node::Not(button(0)))], node("not"));
+
+    evaluate(&mut level);
+    assert!(gate_open(&level, GATE));
+    press(&mut level, 0, true);
+    assert!(!gate_open(&level, GATE));
+}
+
+#[test]
+fn latches_keep_their_value_until_reset() {
+    let latch = Node::Latch {
+        set: button(0),
+        reset: button(1),
+        latched: false,
+    };
+    let mut level = level(2, vec![("latch", latch)], node("latch"));
+
+    press(&mut level, 0, true);
+    press(&mut level, 0, false);
+    assert!(gate_open(&level, GATE));
+
+    press(&mut level, 1, true);
+    assert!(!gate_open(&level, GATE));
+    press(&mut level, 1, false);
+    assert!(!gate_open(&level, GATE));
+}
+
+#[test]
+fn nodes_can_be_chained() {
+    let nodes = vec![
+        ("both", Node::And(vec![button(0), button(1)])),
+        ("neither", Node::Not(node("both"))),
+    ];
+    let mut level = level(2, nodes, node("neither"));
+
+    press(&mut level, 0, true);
+    assert!(gate_open(&level, GATE));
+    press(&mut level, 1, true);
+    assert!(!gate_open(&level, GATE));
+}
+
+#[test]
+fn buttons_switch_all_their_targets() {
+    let mut tile_map = tile_map(&["..", "gg"]);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![
+                ObjectCoordinate { x: 0, y: 1, z: 0 },
+                ObjectCoordinate { x: 1, y: 1, z: 0 },
+            ],
+        },
+    );
+    let mut state = game(tile_map, (0, 0));
+
+    state.tick(0.5, &[Action::Right]);
+    assert!(matches!(
+        tile(&state, 0, 1),
+        TileType::Gate { open: true, .. }
+    ));
+    assert!(matches!(
+        tile(&state, 1, 1),
+        TileType::Gate { open: true, .. }
+    ));
+}
+
+#[test]
+fn the_wiring_is_evaluated_every_tick() {
+    let mut tile_map = tile_map(&["..", "g"]);
+    tile_map.insert(
+        ObjectCoordinate { x: 1, y: 0, z: 0 },
+        TileType::Button {
+            pressed: false,
+            inverted: false,
+            targets: vec![],
+        },
+    );
+    tile_map.insert(coord(0, 0), TileType::Start);
+    let mut level = template(tile_map, MovementMode::Continuous);
+    level.init_state.wiring.outputs.push(Output {
+        signal: Signal::Tile(ObjectCoordinate { x: 1, y: 0, z: 0 }),
+        target: GATE,
+    });
+    let mut state = GameState::new(std::rc::Rc::new(level)).unwrap();
+
+    state.tick(0.5, &[Action::Right]);
+    assert!(matches!(
+        tile(&state, 0, 1),
+        TileType::Gate { open: true, .. }
+    ));
+}
+
+#[test]
+fn invalid_wiring_is_rejected() {
+    let cycle = level(
+        0,
+        vec![("a", Node::Not(node("b"))), ("b", Node::Not(node("a")))],
+        node("a"),
+    );
+    assert!(matches!(
+        cycle.validate_wiring(),
+        Err(ValidationError::WiringCycle(_))
+    ));
+
+    let unknown = level(0, vec![], node("missing"));
+    assert_eq!(
+        unknown.validate_wiring(),
+        Err(ValidationError::UnknownNode("missing".to_string()))
+    );
+
+    let mut path_output = level(1, vec![], button(0));
+    path_output.wiring.outputs[0].target = coord(0, 0);
+    assert_eq!(
+        path_output.validate_wiring(),
+        Err(ValidationError::InvalidOutput(coord(0, 0)))
+    );
+}
+
+#[test]
+fn buttons_with_a_single_target_are_still_read() {
+    let button: TileType =
+        ron::from_str("Button(pressed: false, inverted: true, target: (x: 1, y: 2))").unwrap();
+    assert!(matches!(
+        button,
+        TileType::Button { targets, .. } if targets == [ObjectCoordinate { x: 1, y: 2, z: 0 }]
+    ));
+}
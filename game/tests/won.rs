@@ -0,0 +1,71 @@
+mod common;
+
+use common::*;
+use learning_conrod_game::game::level::loading::load_levels;
+use learning_conrod_game::game::*;
+
+/// Two paths and an active goal, the player starts on the first path
+fn corridor() -> GameState {
+    let mut tile_map = tile_map(&["..."]);
+    tile_map.insert(
+        ObjectCoordinate { x: 2, y: 0, z: 0 },
+        TileType::Goal { active: true },
+    );
+    game_with(tile_map, (0, 0), MovementMode::Grid)
+}
+
+#[test]
+fn reaching_the_goal_keeps_the_stats() {
+    let mut state = corridor();
+
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Right);
+
+    match state {
+        GameState::Won { stats, .. } => {
+            assert_eq!(stats.moves, 2);
+            assert_close(stats.time, 2.0 * (MOVE_TICKS + 1) as f32 * TICK);
+        }
+        GameState::GameState { .. } => panic!("the level should be won"),
+    }
+}
+
+#[test]
+fn retrying_starts_the_level_again() {
+    let mut state = corridor();
+    step(&mut state, Action::Right);
+    step(&mut state, Action::Right);
+
+    let state = state.retry().unwrap();
+
+    assert_eq!(position(&state), (0.0, 0.0));
+    assert_eq!(moves(&state), 0);
+}
+
+#[test]
+fn levels_are_loaded_in_the_order_of_their_file_names() {
+    let assets = std::env::temp_dir().join(format!("level_order_{}", std::process::id()));
+    let levels = assets.join("levels");
+    std::fs::create_dir_all(&levels).unwrap();
+    for (file, name) in [
+        ("02_b.ron", "Second"),
+        ("01_a.ron", "First"),
+        ("10.ron", "Third"),
+    ] {
+        let mut tile_map = tile_map(&[""]);
+        tile_map.insert(ObjectCoordinate { x: 0, y: 0, z: 0 }, TileType::Start);
+        let mut level = template(tile_map, MovementMode::Continuous);
+        level.name = name.to_string();
+        std::fs::write(levels.join(file), ron::to_string(&level).unwrap()).unwrap();
+    }
+
+    let loaded = load_levels(&assets);
+    std::fs::remove_dir_all(&assets).unwrap();
+
+    let names: Vec<_> = loaded
+        .unwrap_or_else(|_| panic!("the levels should load"))
+        .into_iter()
+        .map(|level| level.name)
+        .collect();
+    assert_eq!(names, ["First", "Second", "Third"]);
+}